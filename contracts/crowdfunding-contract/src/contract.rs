@@ -1,21 +1,90 @@
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 use crate::{
     methods::{
         add_campaign::add_campaign,
         add_proof::add_proof,
+        amendment::{
+            approve_milestone_amendment, get_milestone_amendment, propose_milestone_amendment,
+            vote_milestone_amendment,
+        },
+        audit::{
+            add_auditor, annotate_proof, get_proof_annotations, remove_auditor, set_auditor_veto,
+        },
+        budget::get_budget_variance,
+        cancel_campaign::cancel_campaign,
+        config::{
+            get_config, set_allowed_uri_schemes, set_goal_bounds, set_max_milestones,
+            set_text_limits,
+        },
         contribute::contribute,
+        contributor::{get_leaderboard, list_contributors},
+        escrow::{attribute_clawback, reconcile, sweep_surplus},
+        export::export_campaign,
+        finalize_campaign::{claim_leftover, finalize_campaign, get_settlement},
+        fraud::{claim_clawback, freeze_campaign, get_creator_fraud_findings, get_fraud_finding},
         get_campaign::get_campaign,
         get_proof::get_proof,
         initialize::initialize,
-        milestone::{add_milestone, get_campaign_milestones, get_milestone},
-        proof_milestone::validate_milestone_with_proof,
+        kpi::get_campaign_impact,
+        milestone::{
+            add_milestone, get_campaign_milestones, get_milestone, remove_milestone,
+            reorder_milestones, update_milestone,
+        },
+        moderator::{add_moderator, remove_moderator},
+        organization::{
+            add_organization_controller, get_organization, get_organization_campaigns,
+            register_organization, remove_organization_controller, verify_organization,
+        },
+        portfolio::get_donor_portfolio,
+        preview::{preview_contribute, preview_refund, preview_withdraw},
+        proof_milestone::{reject_milestone, validate_milestone_with_proof},
         refund::refund,
+        release::{cancel_milestone_release, set_release_delay},
+        report::{
+            dismiss_reports, get_campaign_reports, get_report_queue, get_report_threshold,
+            report_campaign, set_report_threshold, uphold_reports,
+        },
+        reputation::{get_reputation, rate_campaign, reputation, uphold_challenge},
+        review::{
+            approve_campaign, get_campaign_reviews, reject_campaign, request_campaign_changes,
+            resubmit_campaign,
+        },
+        revoke_proof::revoke_proof,
+        round::{
+            claim_round_return, create_round, enroll_campaign, finalize_round, fund_round,
+            get_round,
+        },
+        statement::get_giving_statement,
+        stats::get_campaign_stats,
         withdraw::withdraw,
         withdraw_milestone::withdraw_milestone_funds,
     },
     storage::{
-        structs::{campaign::Campaign, milestone::Milestone, proof::Proof},
+        structs::{
+            amendment::MilestoneAmendment,
+            audit::{AuditVerdict, ProofAnnotation},
+            budget::{BudgetLineItem, BudgetVariance, SpendReport},
+            campaign::Campaign,
+            config::Config,
+            contributor::ContributorEntry,
+            escrow::Reconciliation,
+            export::CampaignExport,
+            fraud::FraudFinding,
+            kpi::{CampaignImpact, Kpi, KpiReport},
+            milestone::{Milestone, MilestoneDraft, Vesting},
+            organization::{Organization, VerificationLevel},
+            portfolio::PortfolioEntry,
+            preview::{ContributePreview, RefundPreview, WithdrawPreview},
+            proof::Proof,
+            report::{CampaignReports, ReportCategory},
+            reputation::Reputation,
+            review::ReviewEntry,
+            round::Round,
+            settlement::CampaignSettlement,
+            statement::GivingStatement,
+            stats::CampaignStats,
+        },
         types::error::Error,
     },
 };
@@ -29,24 +98,61 @@ impl CrowdfundingContract {
         initialize(&env, admin, token)
     }
 
+    // === CONFIG FUNCTIONS ===
+    pub fn set_text_limits(
+        env: Env,
+        max_id_length: u32,
+        max_title_length: u32,
+        max_description_length: u32,
+        max_uri_length: u32,
+    ) -> Result<(), Error> {
+        set_text_limits(
+            &env,
+            max_id_length,
+            max_title_length,
+            max_description_length,
+            max_uri_length,
+        )
+    }
+
+    pub fn set_max_milestones(env: Env, max_milestones: u32) -> Result<(), Error> {
+        set_max_milestones(&env, max_milestones)
+    }
+
+    pub fn set_goal_bounds(env: Env, min_goal: i128, max_goal: i128) -> Result<(), Error> {
+        set_goal_bounds(&env, min_goal, max_goal)
+    }
+
+    pub fn set_allowed_uri_schemes(env: Env, schemes: Vec<String>) -> Result<(), Error> {
+        set_allowed_uri_schemes(&env, schemes)
+    }
+
+    pub fn get_config(env: Env) -> Config {
+        get_config(&env)
+    }
+
     // === CAMPAIGN FUNCTIONS ===
     pub fn add_campaign(
         env: Env,
         campaign_id: String,
         creator: Address,
+        organization_id: Option<u32>,
         title: String,
         description: String,
         goal: i128,
         min_donation: i128,
+        fallback_beneficiary: Option<Address>,
     ) -> Result<(), Error> {
         add_campaign(
             &env,
             campaign_id,
             creator,
+            organization_id,
             title,
             description,
             goal,
             min_donation,
+            fallback_beneficiary,
         )
     }
 
@@ -54,14 +160,212 @@ impl CrowdfundingContract {
         get_campaign(&env, &campaign_id)
     }
 
+    pub fn cancel_campaign(env: Env, campaign_id: String) -> Result<(), Error> {
+        cancel_campaign(&env, campaign_id)
+    }
+
+    pub fn finalize_campaign(env: Env, campaign_id: String) -> Result<CampaignSettlement, Error> {
+        finalize_campaign(&env, campaign_id)
+    }
+
+    pub fn claim_leftover(env: Env, contributor: Address, campaign_id: String) -> Result<i128, Error> {
+        claim_leftover(&env, contributor, campaign_id)
+    }
+
+    pub fn get_settlement(env: Env, campaign_id: String) -> Result<CampaignSettlement, Error> {
+        get_settlement(&env, &campaign_id)
+    }
+
+    pub fn get_campaign_stats(env: Env, campaign_id: String) -> Result<CampaignStats, Error> {
+        get_campaign_stats(&env, &campaign_id)
+    }
+
+    pub fn export_campaign(
+        env: Env,
+        campaign_id: String,
+        cursor: u32,
+    ) -> Result<CampaignExport, Error> {
+        export_campaign(&env, &campaign_id, cursor)
+    }
+
+    // === ORGANIZATION FUNCTIONS ===
+    pub fn register_organization(
+        env: Env,
+        controller: Address,
+        name: String,
+        registration_hash: BytesN<32>,
+        website: String,
+    ) -> Result<u32, Error> {
+        register_organization(&env, controller, name, registration_hash, website)
+    }
+
+    pub fn add_organization_controller(
+        env: Env,
+        controller: Address,
+        organization_id: u32,
+        new_controller: Address,
+    ) -> Result<(), Error> {
+        add_organization_controller(&env, controller, organization_id, new_controller)
+    }
+
+    pub fn remove_organization_controller(
+        env: Env,
+        controller: Address,
+        organization_id: u32,
+        removed_controller: Address,
+    ) -> Result<(), Error> {
+        remove_organization_controller(&env, controller, organization_id, removed_controller)
+    }
+
+    pub fn verify_organization(
+        env: Env,
+        moderator: Address,
+        organization_id: u32,
+        level: VerificationLevel,
+    ) -> Result<(), Error> {
+        verify_organization(&env, moderator, organization_id, level)
+    }
+
+    pub fn get_organization(env: Env, organization_id: u32) -> Result<Organization, Error> {
+        get_organization(&env, organization_id)
+    }
+
+    pub fn get_organization_campaigns(
+        env: Env,
+        organization_id: u32,
+    ) -> Result<Vec<String>, Error> {
+        get_organization_campaigns(&env, organization_id)
+    }
+
+    // === REVIEW FUNCTIONS ===
+    pub fn add_moderator(env: Env, moderator: Address) -> Result<(), Error> {
+        add_moderator(&env, moderator)
+    }
+
+    pub fn remove_moderator(env: Env, moderator: Address) -> Result<(), Error> {
+        remove_moderator(&env, moderator)
+    }
+
+    pub fn approve_campaign(
+        env: Env,
+        moderator: Address,
+        campaign_id: String,
+    ) -> Result<(), Error> {
+        approve_campaign(&env, moderator, campaign_id)
+    }
+
+    pub fn reject_campaign(
+        env: Env,
+        moderator: Address,
+        campaign_id: String,
+        reason: String,
+    ) -> Result<(), Error> {
+        reject_campaign(&env, moderator, campaign_id, reason)
+    }
+
+    pub fn request_campaign_changes(
+        env: Env,
+        moderator: Address,
+        campaign_id: String,
+        reason: String,
+    ) -> Result<(), Error> {
+        request_campaign_changes(&env, moderator, campaign_id, reason)
+    }
+
+    pub fn resubmit_campaign(
+        env: Env,
+        campaign_id: String,
+        title: String,
+        description: String,
+    ) -> Result<(), Error> {
+        resubmit_campaign(&env, campaign_id, title, description)
+    }
+
+    pub fn get_campaign_reviews(env: Env, campaign_id: String) -> Result<Vec<ReviewEntry>, Error> {
+        get_campaign_reviews(&env, &campaign_id)
+    }
+
     // === MILESTONE FUNCTIONS ===
     pub fn add_milestone(
         env: Env,
         campaign_id: String,
         target_amount: i128,
         description: String,
+        due_date: Option<u64>,
+        budget: Vec<BudgetLineItem>,
+        kpis: Vec<Kpi>,
+        vesting: Vesting,
     ) -> Result<u32, Error> {
-        add_milestone(&env, campaign_id, target_amount, description)
+        add_milestone(
+            &env,
+            campaign_id,
+            target_amount,
+            description,
+            due_date,
+            budget,
+            kpis,
+            vesting,
+        )
+    }
+
+    pub fn update_milestone(
+        env: Env,
+        campaign_id: String,
+        sequence: u32,
+        target_amount: i128,
+        description: String,
+        due_date: Option<u64>,
+        budget: Vec<BudgetLineItem>,
+        kpis: Vec<Kpi>,
+        vesting: Vesting,
+    ) -> Result<(), Error> {
+        update_milestone(
+            &env,
+            campaign_id,
+            sequence,
+            target_amount,
+            description,
+            due_date,
+            budget,
+            kpis,
+            vesting,
+        )
+    }
+
+    pub fn remove_milestone(env: Env, campaign_id: String, sequence: u32) -> Result<(), Error> {
+        remove_milestone(&env, campaign_id, sequence)
+    }
+
+    pub fn reorder_milestones(env: Env, campaign_id: String, order: Vec<u32>) -> Result<(), Error> {
+        reorder_milestones(&env, campaign_id, order)
+    }
+
+    pub fn propose_milestone_amendment(
+        env: Env,
+        campaign_id: String,
+        plan: Vec<MilestoneDraft>,
+        reason: String,
+    ) -> Result<(), Error> {
+        propose_milestone_amendment(&env, campaign_id, plan, reason)
+    }
+
+    pub fn vote_milestone_amendment(
+        env: Env,
+        donor: Address,
+        campaign_id: String,
+    ) -> Result<(), Error> {
+        vote_milestone_amendment(&env, donor, campaign_id)
+    }
+
+    pub fn approve_milestone_amendment(env: Env, campaign_id: String) -> Result<(), Error> {
+        approve_milestone_amendment(&env, campaign_id)
+    }
+
+    pub fn get_milestone_amendment(
+        env: Env,
+        campaign_id: String,
+    ) -> Result<MilestoneAmendment, Error> {
+        get_milestone_amendment(&env, &campaign_id)
     }
 
     pub fn get_milestone(env: Env, campaign_id: String, sequence: u32) -> Result<Milestone, Error> {
@@ -79,8 +383,18 @@ impl CrowdfundingContract {
         campaign_id: String,
         uri: String,
         description: String,
+        spend: Vec<SpendReport>,
+        kpi_values: Vec<KpiReport>,
     ) -> Result<(), Error> {
-        add_proof(&env, proof_id, campaign_id, uri, description)
+        add_proof(
+            &env,
+            proof_id,
+            campaign_id,
+            uri,
+            description,
+            spend,
+            kpi_values,
+        )
     }
 
     pub fn get_proof(env: Env, campaign_id: String, proof_id: String) -> Result<Proof, Error> {
@@ -96,20 +410,285 @@ impl CrowdfundingContract {
         validate_milestone_with_proof(&env, campaign_id, milestone_sequence, proof_id)
     }
 
+    pub fn revoke_proof(
+        env: Env,
+        campaign_id: String,
+        proof_id: String,
+        reason: String,
+    ) -> Result<(), Error> {
+        revoke_proof(&env, campaign_id, proof_id, reason)
+    }
+
+    pub fn reject_milestone(
+        env: Env,
+        campaign_id: String,
+        milestone_sequence: u32,
+        reason: String,
+    ) -> Result<(), Error> {
+        reject_milestone(&env, campaign_id, milestone_sequence, reason)
+    }
+
+    // === AUDIT FUNCTIONS ===
+    pub fn add_auditor(env: Env, auditor: Address) -> Result<(), Error> {
+        add_auditor(&env, auditor)
+    }
+
+    pub fn remove_auditor(env: Env, auditor: Address) -> Result<(), Error> {
+        remove_auditor(&env, auditor)
+    }
+
+    pub fn annotate_proof(
+        env: Env,
+        auditor: Address,
+        campaign_id: String,
+        proof_id: String,
+        verdict: AuditVerdict,
+        comment_uri: String,
+        content_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        annotate_proof(
+            &env,
+            auditor,
+            campaign_id,
+            proof_id,
+            verdict,
+            comment_uri,
+            content_hash,
+        )
+    }
+
+    pub fn set_auditor_veto(env: Env, enabled: bool) -> Result<(), Error> {
+        set_auditor_veto(&env, enabled)
+    }
+
+    pub fn get_proof_annotations(
+        env: Env,
+        campaign_id: String,
+        proof_id: String,
+    ) -> Vec<ProofAnnotation> {
+        get_proof_annotations(&env, &campaign_id, &proof_id)
+    }
+
+    // === BUDGET FUNCTIONS ===
+    pub fn get_budget_variance(
+        env: Env,
+        campaign_id: String,
+        threshold_bps: u32,
+    ) -> Result<BudgetVariance, Error> {
+        get_budget_variance(&env, &campaign_id, threshold_bps)
+    }
+
+    // === IMPACT FUNCTIONS ===
+    pub fn get_campaign_impact(env: Env, campaign_id: String) -> Result<CampaignImpact, Error> {
+        get_campaign_impact(&env, &campaign_id)
+    }
+
     // === CONTRIBUTION & REFUND FUNCTIONS ===
     pub fn contribute(
         env: Env,
         contributor: Address,
         campaign_id: String,
         amount: i128,
+        anonymous: bool,
     ) -> Result<(), Error> {
-        contribute(&env, contributor, campaign_id, amount)
+        contribute(&env, contributor, campaign_id, amount, anonymous)
     }
 
     pub fn refund(env: Env, contributor: Address, campaign_id: String) -> Result<(), Error> {
         refund(&env, contributor, campaign_id)
     }
 
+    pub fn get_donor_portfolio(
+        env: Env,
+        donor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<PortfolioEntry>, Error> {
+        get_donor_portfolio(&env, &donor, offset, limit)
+    }
+
+    pub fn list_contributors(
+        env: Env,
+        campaign_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<ContributorEntry> {
+        list_contributors(&env, &campaign_id, offset, limit)
+    }
+
+    pub fn get_leaderboard(env: Env, campaign_id: String) -> Vec<ContributorEntry> {
+        get_leaderboard(&env, &campaign_id)
+    }
+
+    pub fn get_giving_statement(
+        env: Env,
+        donor: Address,
+        year: u32,
+    ) -> Result<GivingStatement, Error> {
+        get_giving_statement(&env, &donor, year)
+    }
+
+    // === REPUTATION FUNCTIONS ===
+    pub fn rate_campaign(
+        env: Env,
+        donor: Address,
+        campaign_id: String,
+        rating: u32,
+    ) -> Result<(), Error> {
+        rate_campaign(&env, donor, campaign_id, rating)
+    }
+
+    pub fn uphold_challenge(
+        env: Env,
+        moderator: Address,
+        campaign_id: String,
+        reason: String,
+    ) -> Result<(), Error> {
+        uphold_challenge(&env, moderator, campaign_id, reason)
+    }
+
+    pub fn get_reputation(env: Env, creator: Address) -> Reputation {
+        get_reputation(&env, &creator)
+    }
+
+    pub fn reputation(env: Env, creator: Address) -> u32 {
+        reputation(&env, &creator)
+    }
+
+    // === REPORT FUNCTIONS ===
+    pub fn report_campaign(
+        env: Env,
+        reporter: Address,
+        campaign_id: String,
+        category: ReportCategory,
+        evidence_uri: String,
+    ) -> Result<(), Error> {
+        report_campaign(&env, reporter, campaign_id, category, evidence_uri)
+    }
+
+    pub fn dismiss_reports(
+        env: Env,
+        moderator: Address,
+        campaign_id: String,
+        reason: String,
+    ) -> Result<(), Error> {
+        dismiss_reports(&env, moderator, campaign_id, reason)
+    }
+
+    pub fn uphold_reports(
+        env: Env,
+        moderator: Address,
+        campaign_id: String,
+        reason: String,
+    ) -> Result<(), Error> {
+        uphold_reports(&env, moderator, campaign_id, reason)
+    }
+
+    pub fn set_report_threshold(env: Env, threshold_bps: u32) -> Result<(), Error> {
+        set_report_threshold(&env, threshold_bps)
+    }
+
+    pub fn get_report_threshold(env: Env) -> u32 {
+        get_report_threshold(&env)
+    }
+
+    pub fn get_campaign_reports(env: Env, campaign_id: String) -> CampaignReports {
+        get_campaign_reports(&env, &campaign_id)
+    }
+
+    pub fn get_report_queue(env: Env) -> Vec<String> {
+        get_report_queue(&env)
+    }
+
+    // === FRAUD RESPONSE FUNCTIONS ===
+    pub fn freeze_campaign(
+        env: Env,
+        campaign_id: String,
+        evidence_uri: String,
+        evidence_hash: BytesN<32>,
+    ) -> Result<FraudFinding, Error> {
+        freeze_campaign(&env, campaign_id, evidence_uri, evidence_hash)
+    }
+
+    pub fn claim_clawback(
+        env: Env,
+        contributor: Address,
+        campaign_id: String,
+    ) -> Result<i128, Error> {
+        claim_clawback(&env, contributor, campaign_id)
+    }
+
+    pub fn get_fraud_finding(env: Env, campaign_id: String) -> Result<FraudFinding, Error> {
+        get_fraud_finding(&env, &campaign_id)
+    }
+
+    pub fn get_creator_fraud_findings(env: Env, creator: Address) -> Vec<String> {
+        get_creator_fraud_findings(&env, &creator)
+    }
+
+    // === MATCHING ROUND FUNCTIONS ===
+    pub fn create_round(env: Env, name: String) -> Result<u32, Error> {
+        create_round(&env, name)
+    }
+
+    pub fn enroll_campaign(env: Env, round_id: u32, campaign_id: String) -> Result<(), Error> {
+        enroll_campaign(&env, round_id, campaign_id)
+    }
+
+    pub fn fund_round(env: Env, funder: Address, round_id: u32, amount: i128) -> Result<(), Error> {
+        fund_round(&env, funder, round_id, amount)
+    }
+
+    pub fn finalize_round(env: Env, round_id: u32) -> Result<(), Error> {
+        finalize_round(&env, round_id)
+    }
+
+    pub fn claim_round_return(env: Env, funder: Address, round_id: u32) -> Result<i128, Error> {
+        claim_round_return(&env, funder, round_id)
+    }
+
+    pub fn get_round(env: Env, round_id: u32) -> Result<Round, Error> {
+        get_round(&env, round_id)
+    }
+
+    // === ESCROW FUNCTIONS ===
+    pub fn attribute_clawback(env: Env, campaign_id: String, amount: i128) -> Result<i128, Error> {
+        attribute_clawback(&env, campaign_id, amount)
+    }
+
+    pub fn reconcile(env: Env) -> Result<Reconciliation, Error> {
+        reconcile(&env)
+    }
+
+    pub fn sweep_surplus(env: Env, to: Address) -> Result<i128, Error> {
+        sweep_surplus(&env, to)
+    }
+
+    // === PREVIEW FUNCTIONS ===
+    pub fn preview_contribute(
+        env: Env,
+        campaign_id: String,
+        amount: i128,
+    ) -> Result<ContributePreview, Error> {
+        preview_contribute(&env, &campaign_id, amount)
+    }
+
+    pub fn preview_refund(
+        env: Env,
+        contributor: Address,
+        campaign_id: String,
+    ) -> Result<RefundPreview, Error> {
+        preview_refund(&env, &contributor, &campaign_id)
+    }
+
+    pub fn preview_withdraw(
+        env: Env,
+        campaign_id: String,
+        milestone_sequence: u32,
+    ) -> Result<WithdrawPreview, Error> {
+        preview_withdraw(&env, &campaign_id, milestone_sequence)
+    }
+
     // === WITHDRAWAL FUNCTIONS ===
     pub fn withdraw_milestone_funds(
         env: Env,
@@ -119,6 +698,23 @@ impl CrowdfundingContract {
         withdraw_milestone_funds(&env, campaign_id, milestone_sequence)
     }
 
+    pub fn set_release_delay(
+        env: Env,
+        campaign_id: String,
+        release_delay: u64,
+    ) -> Result<(), Error> {
+        set_release_delay(&env, campaign_id, release_delay)
+    }
+
+    pub fn cancel_milestone_release(
+        env: Env,
+        campaign_id: String,
+        milestone_sequence: u32,
+        reason: String,
+    ) -> Result<(), Error> {
+        cancel_milestone_release(&env, campaign_id, milestone_sequence, reason)
+    }
+
     // This function is for non-milestone campaigns. Use with caution.
     pub fn withdraw(env: Env, campaign_id: String) -> Result<(), Error> {
         withdraw(&env, campaign_id)
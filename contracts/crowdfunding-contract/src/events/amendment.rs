@@ -0,0 +1,25 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+/// Event emitted when a creator proposes a milestone plan amendment
+pub(crate) fn amendment_proposed(env: &Env, campaign_id: String, first_sequence: u32) {
+    env.events().publish(
+        (symbol_short!("amendment"), symbol_short!("proposed")),
+        (campaign_id, first_sequence),
+    );
+}
+
+/// Event emitted when a donor approves a pending amendment
+pub(crate) fn amendment_voted(env: &Env, campaign_id: String, donor: &Address, weight: i128) {
+    env.events().publish(
+        (symbol_short!("amendment"), symbol_short!("voted")),
+        (campaign_id, donor.clone(), weight),
+    );
+}
+
+/// Event emitted when an amendment replaces the milestone plan
+pub(crate) fn amendment_applied(env: &Env, campaign_id: String, milestones_count: u32) {
+    env.events().publish(
+        (symbol_short!("amendment"), symbol_short!("applied")),
+        (campaign_id, milestones_count),
+    );
+}
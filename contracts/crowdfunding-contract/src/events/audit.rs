@@ -0,0 +1,44 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+use crate::storage::structs::audit::AuditVerdict;
+
+/// Event emitted when an auditor annotates a proof
+pub(crate) fn proof_annotated(
+    env: &Env,
+    campaign_id: &String,
+    proof_id: &String,
+    auditor: &Address,
+    verdict: AuditVerdict,
+) {
+    env.events().publish(
+        (symbol_short!("proof"), symbol_short!("annotated")),
+        (
+            campaign_id.clone(),
+            proof_id.clone(),
+            auditor.clone(),
+            verdict,
+        ),
+    );
+}
+
+/// Event emitted when the admin grants auditor rights
+pub(crate) fn auditor_added(env: &Env, auditor: &Address) {
+    env.events().publish(
+        (symbol_short!("auditor"), symbol_short!("added")),
+        auditor.clone(),
+    );
+}
+
+/// Event emitted when the admin revokes auditor rights
+pub(crate) fn auditor_removed(env: &Env, auditor: &Address) {
+    env.events().publish(
+        (symbol_short!("auditor"), symbol_short!("removed")),
+        auditor.clone(),
+    );
+}
+
+/// Event emitted when the admin turns the auditor veto on or off
+pub(crate) fn auditor_veto_set(env: &Env, enabled: bool) {
+    env.events()
+        .publish((symbol_short!("auditor"), symbol_short!("veto")), enabled);
+}
@@ -1,11 +1,16 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, Env, String, Symbol};
 
 pub(crate) fn add_campaign(env: &Env, creator: &Address, goal: &i128) {
     let topics = (Symbol::new(env, "add_campaign"), creator);
     env.events().publish(topics, goal);
 }
 
-pub (crate) fn withdraw(env: &Env, creator: &Address, total_raised: i128) {
+pub(crate) fn withdraw(env: &Env, creator: &Address, total_raised: i128) {
     let topics = (Symbol::new(env, "withdraw"), creator);
-    env.events().publish(topics, &total_raised);
-}
\ No newline at end of file
+    env.events().publish(topics, total_raised);
+}
+
+pub(crate) fn cancel_campaign(env: &Env, creator: &Address, campaign_id: &String) {
+    let topics = (Symbol::new(env, "cancel_campaign"), creator);
+    env.events().publish(topics, campaign_id.clone());
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::{symbol_short, Env};
+
+use crate::storage::structs::config::Config;
+
+/// Event emitted when the admin changes the platform limits
+pub(crate) fn config_updated(env: &Env, config: &Config) {
+    env.events().publish(
+        (symbol_short!("config"), symbol_short!("updated")),
+        config.clone(),
+    );
+}
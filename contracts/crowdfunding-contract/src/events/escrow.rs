@@ -0,0 +1,17 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+/// Event emitted when the admin sweeps tokens held above the contract's liabilities
+pub(crate) fn surplus_swept(env: &Env, to: &Address, amount: i128, liabilities: i128) {
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("swept")),
+        (to.clone(), amount, liabilities),
+    );
+}
+
+/// Event emitted when the admin charges clawed back escrow to a campaign
+pub(crate) fn clawback_attributed(env: &Env, campaign_id: String, amount: i128) {
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("clawback")),
+        (campaign_id, amount),
+    );
+}
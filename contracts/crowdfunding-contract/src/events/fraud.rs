@@ -0,0 +1,43 @@
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String};
+
+/// Event emitted when the admin freezes a campaign over a fraud finding
+pub(crate) fn campaign_frozen(
+    env: &Env,
+    campaign_id: String,
+    creator: &Address,
+    evidence_uri: String,
+    evidence_hash: BytesN<32>,
+    recoverable_amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("fraud"), symbol_short!("frozen")),
+        (
+            campaign_id,
+            creator.clone(),
+            evidence_uri,
+            evidence_hash,
+            recoverable_amount,
+        ),
+    );
+}
+
+/// Event emitted when a contributor claims their share of a frozen campaign's escrow
+pub(crate) fn clawback_claimed(
+    env: &Env,
+    campaign_id: String,
+    contributor: &Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("fraud"), symbol_short!("clawback")),
+        (campaign_id, contributor.clone(), amount),
+    );
+}
+
+/// Event emitted when a proof is revoked after the funds it unlocked were released
+pub(crate) fn fraud_flagged(env: &Env, campaign_id: String, creator: &Address, proof_id: String) {
+    env.events().publish(
+        (symbol_short!("fraud"), symbol_short!("flagged")),
+        (campaign_id, creator.clone(), proof_id),
+    );
+}
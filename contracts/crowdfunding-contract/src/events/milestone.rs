@@ -1,4 +1,4 @@
-use soroban_sdk::{symbol_short, Env, String};
+use soroban_sdk::{symbol_short, Env, String, Vec};
 
 /// Event emitted when a new milestone is created for a campaign
 pub(crate) fn milestone_created(
@@ -38,3 +38,69 @@ pub(crate) fn milestone_withdrawal(
         (campaign_id, milestone_sequence, amount),
     );
 }
+
+/// Event emitted when the proof for a milestone is rejected
+pub(crate) fn milestone_rejected(
+    env: &Env,
+    campaign_id: String,
+    milestone_sequence: u32,
+    reason: String,
+) {
+    env.events().publish(
+        (symbol_short!("milestone"), symbol_short!("rejected")),
+        (campaign_id, milestone_sequence, reason),
+    );
+}
+
+/// Event emitted when a milestone of an editable plan is updated
+pub(crate) fn milestone_updated(
+    env: &Env,
+    campaign_id: String,
+    sequence: u32,
+    target_amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("milestone"), symbol_short!("updated")),
+        (campaign_id, sequence, target_amount),
+    );
+}
+
+/// Event emitted when a milestone of an editable plan is removed
+pub(crate) fn milestone_removed(env: &Env, campaign_id: String, sequence: u32) {
+    env.events().publish(
+        (symbol_short!("milestone"), symbol_short!("removed")),
+        (campaign_id, sequence),
+    );
+}
+
+/// Event emitted when the milestones of an editable plan are reordered
+pub(crate) fn milestones_reordered(env: &Env, campaign_id: String, order: Vec<u32>) {
+    env.events().publish(
+        (symbol_short!("milestone"), symbol_short!("reordered")),
+        (campaign_id, order),
+    );
+}
+
+/// Event emitted when the release delay of a campaign is changed
+pub(crate) fn release_delay_set(env: &Env, campaign_id: String, release_delay: u64) {
+    env.events().publish(
+        (symbol_short!("milestone"), symbol_short!("delay")),
+        (campaign_id, release_delay),
+    );
+}
+
+/// Event emitted when a pending milestone release is cancelled
+pub(crate) fn release_cancelled(env: &Env, campaign_id: String, sequence: u32, reason: String) {
+    env.events().publish(
+        (symbol_short!("milestone"), symbol_short!("cancelled")),
+        (campaign_id, sequence, reason),
+    );
+}
+
+/// Event emitted when a milestone rolls back to incomplete after its proof is revoked
+pub(crate) fn milestone_rolled_back(env: &Env, campaign_id: String, sequence: u32) {
+    env.events().publish(
+        (symbol_short!("milestone"), symbol_short!("rollback")),
+        (campaign_id, sequence),
+    );
+}
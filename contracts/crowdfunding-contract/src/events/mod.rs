@@ -1,6 +1,17 @@
+pub mod amendment;
+pub mod audit;
 pub mod campaign;
+pub mod config;
 pub mod contract;
 pub mod contribute;
+pub mod escrow;
+pub mod fraud;
 pub mod milestone;
+pub mod organization;
 pub mod proof;
 pub mod refund;
+pub mod report;
+pub mod reputation;
+pub mod review;
+pub mod round;
+pub mod settlement;
@@ -0,0 +1,32 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+use crate::storage::structs::organization::VerificationLevel;
+
+/// Event emitted when a new organisation registers
+pub(crate) fn organization_registered(env: &Env, organization_id: u32, name: String) {
+    env.events().publish(
+        (symbol_short!("org"), symbol_short!("register")),
+        (organization_id, name),
+    );
+}
+
+/// Event emitted when an organisation's controller set changes
+pub(crate) fn controller_updated(
+    env: &Env,
+    organization_id: u32,
+    controller: &Address,
+    enabled: bool,
+) {
+    env.events().publish(
+        (symbol_short!("org"), symbol_short!("control")),
+        (organization_id, controller.clone(), enabled),
+    );
+}
+
+/// Event emitted when a moderator changes an organisation's verification level
+pub(crate) fn organization_verified(env: &Env, organization_id: u32, level: VerificationLevel) {
+    env.events().publish(
+        (symbol_short!("org"), symbol_short!("verified")),
+        (organization_id, level),
+    );
+}
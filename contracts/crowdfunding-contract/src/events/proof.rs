@@ -15,3 +15,11 @@ pub(crate) fn proof_validated(env: &Env, campaign_id: &String, proof_id: &String
         (campaign_id.clone(), proof_id.clone()),
     );
 }
+
+/// Event emitted when the admin revokes a proof
+pub(crate) fn proof_revoked(env: &Env, campaign_id: &String, proof_id: &String, reason: String) {
+    env.events().publish(
+        (symbol_short!("proof"), symbol_short!("revoked")),
+        (campaign_id.clone(), proof_id.clone(), reason),
+    );
+}
@@ -0,0 +1,47 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+use crate::storage::structs::report::{ReportCategory, ReportStatus};
+
+/// Event emitted when a contributor files a report against a campaign
+pub(crate) fn campaign_reported(
+    env: &Env,
+    campaign_id: &String,
+    reporter: &Address,
+    category: ReportCategory,
+    weight: i128,
+) {
+    env.events().publish(
+        (symbol_short!("report"), campaign_id.clone()),
+        (reporter.clone(), category, weight),
+    );
+}
+
+/// Event emitted when reports cross the threshold and withdrawals are paused
+pub(crate) fn review_triggered(env: &Env, campaign_id: &String, pending_weight: i128) {
+    env.events().publish(
+        (symbol_short!("report"), symbol_short!("triggered")),
+        (campaign_id.clone(), pending_weight),
+    );
+}
+
+/// Event emitted when a moderator dismisses or upholds a campaign's reports
+pub(crate) fn reports_resolved(
+    env: &Env,
+    campaign_id: &String,
+    moderator: &Address,
+    status: ReportStatus,
+    reason: String,
+) {
+    env.events().publish(
+        (symbol_short!("report"), symbol_short!("resolved")),
+        (campaign_id.clone(), moderator.clone(), status, reason),
+    );
+}
+
+/// Event emitted when the admin changes the report threshold
+pub(crate) fn threshold_set(env: &Env, threshold_bps: u32) {
+    env.events().publish(
+        (symbol_short!("report"), symbol_short!("threshold")),
+        threshold_bps,
+    );
+}
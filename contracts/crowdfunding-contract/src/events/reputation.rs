@@ -0,0 +1,17 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+/// Event emitted when a donor rates a finished campaign
+pub(crate) fn campaign_rated(env: &Env, campaign_id: &String, donor: &Address, rating: u32) {
+    env.events().publish(
+        (symbol_short!("rating"), campaign_id.clone()),
+        (donor.clone(), rating),
+    );
+}
+
+/// Event emitted when a moderator upholds a challenge against a campaign's creator
+pub(crate) fn challenge_upheld(env: &Env, campaign_id: &String, creator: &Address, reason: String) {
+    env.events().publish(
+        (symbol_short!("challenge"), campaign_id.clone()),
+        (creator.clone(), reason),
+    );
+}
@@ -0,0 +1,32 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+use crate::storage::structs::review::ReviewAction;
+
+/// Event emitted when a moderator or the creator acts on a campaign review
+pub(crate) fn campaign_reviewed(
+    env: &Env,
+    campaign_id: &String,
+    reviewer: &Address,
+    action: ReviewAction,
+) {
+    env.events().publish(
+        (symbol_short!("review"), campaign_id.clone()),
+        (reviewer.clone(), action),
+    );
+}
+
+/// Event emitted when the admin grants moderator rights
+pub(crate) fn moderator_added(env: &Env, moderator: &Address) {
+    env.events().publish(
+        (symbol_short!("moderator"), symbol_short!("added")),
+        moderator.clone(),
+    );
+}
+
+/// Event emitted when the admin revokes moderator rights
+pub(crate) fn moderator_removed(env: &Env, moderator: &Address) {
+    env.events().publish(
+        (symbol_short!("moderator"), symbol_short!("removed")),
+        moderator.clone(),
+    );
+}
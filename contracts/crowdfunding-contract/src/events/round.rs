@@ -0,0 +1,65 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+/// Event emitted when a matching round is created
+pub(crate) fn round_created(env: &Env, round_id: u32, name: String) {
+    env.events().publish(
+        (symbol_short!("round"), symbol_short!("created")),
+        (round_id, name),
+    );
+}
+
+/// Event emitted when a campaign is enrolled in a matching round
+pub(crate) fn campaign_enrolled(env: &Env, round_id: u32, campaign_id: String) {
+    env.events().publish(
+        (symbol_short!("round"), symbol_short!("enrolled")),
+        (round_id, campaign_id),
+    );
+}
+
+/// Event emitted when matching funds are deposited into a round
+pub(crate) fn round_funded(env: &Env, round_id: u32, funder: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("round"), symbol_short!("funded")),
+        (round_id, funder.clone(), amount),
+    );
+}
+
+/// Event emitted for every campaign credited on round finalization
+pub(crate) fn campaign_matched(env: &Env, round_id: u32, campaign_id: String, amount: i128) {
+    env.events().publish(
+        (symbol_short!("round"), symbol_short!("matched")),
+        (round_id, campaign_id, amount),
+    );
+}
+
+/// Event emitted when a matching round is finalized
+pub(crate) fn round_finalized(env: &Env, round_id: u32, distributed: i128) {
+    env.events().publish(
+        (symbol_short!("round"), symbol_short!("finalized")),
+        (round_id, distributed),
+    );
+}
+
+/// Event emitted when an unmatched pool is returned to a funder
+pub(crate) fn deposit_returned(env: &Env, round_id: u32, funder: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("round"), symbol_short!("returned")),
+        (round_id, funder.clone(), amount),
+    );
+}
+
+/// Event emitted when a closed campaign hands matched funds back to a round
+pub(crate) fn match_returned(env: &Env, round_id: u32, campaign_id: String, amount: i128) {
+    env.events().publish(
+        (symbol_short!("round"), symbol_short!("unmatched")),
+        (round_id, campaign_id, amount),
+    );
+}
+
+/// Event emitted when a funder claims matched funds handed back to a round
+pub(crate) fn return_claimed(env: &Env, round_id: u32, funder: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("round"), symbol_short!("reclaimed")),
+        (round_id, funder.clone(), amount),
+    );
+}
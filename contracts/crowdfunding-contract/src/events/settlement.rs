@@ -0,0 +1,22 @@
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+/// Event emitted when a completed campaign is finalized
+pub(crate) fn campaign_finalized(env: &Env, campaign_id: String, leftover_amount: i128) {
+    env.events().publish(
+        (symbol_short!("campaign"), symbol_short!("finalized")),
+        (campaign_id, leftover_amount),
+    );
+}
+
+/// Event emitted when leftover escrow is returned to a contributor
+pub(crate) fn leftover_returned(
+    env: &Env,
+    campaign_id: String,
+    contributor: &Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("campaign"), symbol_short!("leftover")),
+        (campaign_id, contributor.clone(), amount),
+    );
+}
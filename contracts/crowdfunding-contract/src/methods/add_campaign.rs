@@ -2,9 +2,12 @@ use soroban_sdk::{Address, Env, String};
 
 use crate::{
     events,
+    methods::{config::check_length, organization::link_campaign},
     storage::{
         campaign::{has_campaign, set_campaign},
-        structs::campaign::Campaign,
+        config::get_config,
+        settlement::has_settlement,
+        structs::campaign::{Campaign, CampaignStatus},
         types::error::Error,
     },
 };
@@ -13,16 +16,28 @@ pub fn add_campaign(
     env: &Env,
     campaign_id: String,
     creator: Address,
+    organization_id: Option<u32>,
     title: String,
     description: String,
     goal: i128,
     min_donation: i128,
+    fallback_beneficiary: Option<Address>,
 ) -> Result<(), Error> {
     // Verify creator authorization
     creator.require_auth();
 
-    // Validate inputs
-    if goal <= 0 {
+    // Validate inputs against the platform limits
+    let config = get_config(env);
+    check_length(&campaign_id, config.max_id_length, Error::IdTooLong)?;
+    check_length(&title, config.max_title_length, Error::TitleTooLong)?;
+    check_length(
+        &description,
+        config.max_description_length,
+        Error::DescriptionTooLong,
+    )?;
+
+    // Goals must be positive and inside the admin's bounds
+    if goal <= 0 || goal < config.min_goal || goal > config.max_goal {
         return Err(Error::InvalidGoalAmount);
     }
 
@@ -30,26 +45,40 @@ pub fn add_campaign(
         return Err(Error::InvalidMinDonation);
     }
 
-    // Check if campaign already exists
-    if has_campaign(env, &campaign_id) {
+    // Check if campaign already exists, or existed and was archived
+    if has_campaign(env, &campaign_id) || has_settlement(env, &campaign_id) {
         return Err(Error::CampaignAlreadyExists);
     }
 
-    // Create campaign
+    // Create campaign, pending moderator review
     let campaign = Campaign {
         id: campaign_id.clone(),
         creator: creator.clone(),
+        organization_id,
+        fallback_beneficiary,
         title,
         description,
+        status: CampaignStatus::PendingReview,
         goal,
         min_donation,
         total_raised: 0,
         supporters: 0,
+        matched_amount: 0,
+        created_at: env.ledger().timestamp(),
+        cancelled_at: None,
         milestones_count: 0,
+        milestones_locked: false,
         current_milestone: 0,
         withdrawable_amount: 0,
+        release_delay: 0,
+        withdrawals_paused: false,
     };
 
+    // Link to the creator's organisation, if any
+    if let Some(organization_id) = organization_id {
+        link_campaign(env, organization_id, &campaign_id, &creator)?;
+    }
+
     // Store campaign
     set_campaign(env, &campaign_id, &campaign);
 
@@ -1,8 +1,15 @@
 use crate::{
     events,
-    storage::{admin::get_admin, proof::set_proof, structs::proof::Proof, types::error::Error},
+    methods::config::{check_length, check_uri},
+    storage::{
+        admin::get_admin,
+        config::get_config,
+        proof::{add_campaign_proof, get_proof, set_proof},
+        structs::{budget::SpendReport, kpi::KpiReport, proof::Proof},
+        types::error::Error,
+    },
 };
-use soroban_sdk::{Env, String};
+use soroban_sdk::{Env, String, Vec};
 
 pub fn add_proof(
     env: &Env,
@@ -10,19 +17,43 @@ pub fn add_proof(
     campaign_id: String,
     uri: String,
     description: String,
+    spend: Vec<SpendReport>,
+    kpi_values: Vec<KpiReport>,
 ) -> Result<(), Error> {
     let admin = get_admin(env);
     admin.require_auth();
 
+    let config = get_config(env);
+    check_length(&proof_id, config.max_id_length, Error::IdTooLong)?;
+    check_length(
+        &description,
+        config.max_description_length,
+        Error::DescriptionTooLong,
+    )?;
+    check_uri(&config, &uri)?;
+
+    // Proofs stay on record, revoked ones included, and cannot be replaced
+    if let Ok(proof) = get_proof(env, &campaign_id, &proof_id) {
+        return Err(match proof.revoked_at {
+            Some(_) => Error::ProofRevoked,
+            None => Error::ProofAlreadyExists,
+        });
+    }
+
     let proof = Proof {
         id: proof_id.clone(),
         campaign_id: campaign_id.clone(),
         uri,
         description,
         timestamp: env.ledger().timestamp(),
+        spend,
+        kpi_values,
+        revoked_at: None,
+        revocation_reason: None,
     };
 
     set_proof(env, &campaign_id, &proof_id, &proof);
+    add_campaign_proof(env, &campaign_id, &proof_id);
 
     events::proof::proof_logged(env, &campaign_id, &proof_id);
 
@@ -0,0 +1,131 @@
+use crate::events;
+use crate::methods::{
+    math::{add, mul, sub},
+    milestone::replace_plan,
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{amendment::MilestoneAmendment, milestone::MilestoneDraft},
+};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Propose a new plan for the uncompleted milestones (Creator only)
+///
+/// A new proposal replaces any pending one and resets its approvals. The plan
+/// must keep at least one milestone.
+pub fn propose_milestone_amendment(
+    env: &Env,
+    campaign_id: String,
+    plan: Vec<MilestoneDraft>,
+    reason: String,
+) -> Result<(), Error> {
+    let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    campaign.creator.require_auth();
+
+    if plan.is_empty() {
+        return Err(Error::MilestoneNotFound);
+    }
+
+    let amendment = MilestoneAmendment {
+        campaign_id: campaign_id.clone(),
+        first_sequence: campaign.current_milestone + 1,
+        plan,
+        reason,
+        proposed_at: env.ledger().timestamp(),
+        approvals: Vec::new(env),
+        approved_weight: 0,
+    };
+    storage::amendment::set_amendment(env, &campaign_id, &amendment);
+
+    events::amendment::amendment_proposed(env, campaign_id, amendment.first_sequence);
+
+    Ok(())
+}
+
+/// Approve the pending amendment with the donor's contribution as weight
+///
+/// The amendment is applied once approving donors hold more than half of
+/// the campaign's contributions. Approvals are weighed by what each donor
+/// still has in the campaign at the time of the vote, so refunded funds stop
+/// counting.
+pub fn vote_milestone_amendment(
+    env: &Env,
+    donor: Address,
+    campaign_id: String,
+) -> Result<(), Error> {
+    donor.require_auth();
+
+    let mut amendment = storage::amendment::get_amendment(env, &campaign_id)?;
+    let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+
+    let weight = storage::contribution::get_contribution(env, &campaign_id, &donor);
+    if weight <= 0 {
+        return Err(Error::ContributionNotFound);
+    }
+
+    if amendment.approvals.contains(&donor) {
+        return Ok(());
+    }
+
+    amendment.approvals.push_back(donor.clone());
+    amendment.approved_weight = 0;
+    for approver in amendment.approvals.iter() {
+        let contribution = storage::contribution::get_contribution(env, &campaign_id, &approver);
+        amendment.approved_weight = add(amendment.approved_weight, contribution)?;
+    }
+
+    events::amendment::amendment_voted(env, campaign_id.clone(), &donor, weight);
+
+    // Matching funds carry no vote
+    let contributed = sub(campaign.total_raised, campaign.matched_amount)?;
+    if mul(amendment.approved_weight, 2)? > contributed {
+        apply_amendment(env, &campaign_id, &amendment)
+    } else {
+        storage::amendment::set_amendment(env, &campaign_id, &amendment);
+        Ok(())
+    }
+}
+
+/// Approve and apply the pending amendment (Admin only)
+pub fn approve_milestone_amendment(env: &Env, campaign_id: String) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let amendment = storage::amendment::get_amendment(env, &campaign_id)?;
+    apply_amendment(env, &campaign_id, &amendment)
+}
+
+/// Get the pending milestone amendment of a campaign
+pub fn get_milestone_amendment(
+    env: &Env,
+    campaign_id: &String,
+) -> Result<MilestoneAmendment, Error> {
+    storage::amendment::get_amendment(env, campaign_id)
+}
+
+fn apply_amendment(
+    env: &Env,
+    campaign_id: &String,
+    amendment: &MilestoneAmendment,
+) -> Result<(), Error> {
+    let mut campaign = storage::campaign::get_campaign(env, campaign_id)?;
+
+    // A milestone validated after the proposal makes the amendment stale
+    if amendment.first_sequence != campaign.current_milestone + 1 {
+        return Err(Error::MilestoneAlreadyCompleted);
+    }
+
+    replace_plan(
+        env,
+        campaign_id,
+        &mut campaign,
+        amendment.first_sequence,
+        &amendment.plan,
+    )?;
+    storage::amendment::remove_amendment(env, campaign_id);
+
+    events::amendment::amendment_applied(env, campaign_id.clone(), campaign.milestones_count);
+
+    Ok(())
+}
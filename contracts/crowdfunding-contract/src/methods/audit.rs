@@ -0,0 +1,113 @@
+use crate::events;
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::audit::{AuditVerdict, ProofAnnotation},
+};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+/// Grant auditor rights to an address (Admin only)
+pub fn add_auditor(env: &Env, auditor: Address) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    storage::auditor::set_auditor(env, &auditor);
+    events::audit::auditor_added(env, &auditor);
+
+    Ok(())
+}
+
+/// Revoke auditor rights from an address (Admin only)
+///
+/// Annotations already attached by the auditor are kept.
+pub fn remove_auditor(env: &Env, auditor: Address) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    storage::auditor::remove_auditor(env, &auditor);
+    events::audit::auditor_removed(env, &auditor);
+
+    Ok(())
+}
+
+/// Attach an opinion on a proof's evidence (Auditor only)
+///
+/// Annotating the same proof again replaces the auditor's previous
+/// annotation, so a verdict can be revised once concerns are addressed.
+pub fn annotate_proof(
+    env: &Env,
+    auditor: Address,
+    campaign_id: String,
+    proof_id: String,
+    verdict: AuditVerdict,
+    comment_uri: String,
+    content_hash: BytesN<32>,
+) -> Result<(), Error> {
+    auditor.require_auth();
+
+    if !storage::auditor::is_auditor(env, &auditor) {
+        return Err(Error::Unauthorized);
+    }
+
+    storage::proof::get_proof(env, &campaign_id, &proof_id)?;
+
+    let mut annotations = storage::auditor::get_proof_annotations(env, &campaign_id, &proof_id);
+    if let Some(index) = annotations
+        .iter()
+        .position(|annotation| annotation.auditor == auditor)
+    {
+        annotations.remove(index as u32);
+    }
+    annotations.push_back(ProofAnnotation {
+        auditor: auditor.clone(),
+        verdict,
+        comment_uri,
+        content_hash,
+        timestamp: env.ledger().timestamp(),
+    });
+    storage::auditor::set_proof_annotations(env, &campaign_id, &proof_id, &annotations);
+
+    events::audit::proof_annotated(env, &campaign_id, &proof_id, &auditor, verdict);
+
+    Ok(())
+}
+
+/// Let a reject verdict on a proof block milestone validation (Admin only)
+pub fn set_auditor_veto(env: &Env, enabled: bool) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    storage::auditor::set_auditor_veto(env, enabled);
+    events::audit::auditor_veto_set(env, enabled);
+
+    Ok(())
+}
+
+/// Get the auditor annotations attached to a proof
+pub fn get_proof_annotations(
+    env: &Env,
+    campaign_id: &String,
+    proof_id: &String,
+) -> Vec<ProofAnnotation> {
+    storage::auditor::get_proof_annotations(env, campaign_id, proof_id)
+}
+
+/// Check whether the proof may validate a milestone under the auditor veto
+pub(crate) fn check_auditor_veto(
+    env: &Env,
+    campaign_id: &String,
+    proof_id: &String,
+) -> Result<(), Error> {
+    if !storage::auditor::get_auditor_veto(env) {
+        return Ok(());
+    }
+
+    let rejected = storage::auditor::get_proof_annotations(env, campaign_id, proof_id)
+        .iter()
+        .any(|annotation| annotation.verdict == AuditVerdict::Reject);
+    if rejected {
+        return Err(Error::ProofRejectedByAuditor);
+    }
+
+    Ok(())
+}
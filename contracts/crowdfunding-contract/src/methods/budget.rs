@@ -0,0 +1,151 @@
+use crate::methods::math::{add, mul_div, sub, BPS_DENOMINATOR};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{budget::*, milestone::Milestone},
+};
+use soroban_sdk::{Env, String, Vec};
+
+/// Planned-versus-actual spend per line item, per milestone and per campaign
+///
+/// Actual spend comes from the proof that validated each milestone. Line items
+/// and milestones are flagged as overrun when actual spend exceeds the plan by
+/// more than `threshold_bps`. Campaign totals only cover milestones whose
+/// spend has been reported, so unfinished milestones do not show as savings.
+pub fn get_budget_variance(
+    env: &Env,
+    campaign_id: &String,
+    threshold_bps: u32,
+) -> Result<BudgetVariance, Error> {
+    let campaign = storage::campaign::get_campaign(env, campaign_id)?;
+
+    let mut report = BudgetVariance {
+        campaign_id: campaign_id.clone(),
+        threshold_bps,
+        planned_amount: 0,
+        actual_amount: 0,
+        variance: 0,
+        overrun: false,
+        milestones: Vec::new(env),
+    };
+
+    for sequence in 1..=campaign.milestones_count {
+        let milestone = storage::milestone::get_milestone(env, campaign_id, sequence)?;
+        let variance = milestone_variance(env, campaign_id, &milestone, threshold_bps)?;
+
+        if variance.reported {
+            report.planned_amount = add(report.planned_amount, variance.planned_amount)?;
+            report.actual_amount = add(report.actual_amount, variance.actual_amount)?;
+        }
+        report.milestones.push_back(variance);
+    }
+
+    report.variance = sub(report.actual_amount, report.planned_amount)?;
+    report.overrun = exceeds(report.planned_amount, report.actual_amount, threshold_bps)?;
+
+    Ok(report)
+}
+
+/// Check a milestone budget against the tranche it spends
+pub(crate) fn validate_budget(budget: &Vec<BudgetLineItem>, tranche: i128) -> Result<(), Error> {
+    let mut planned: i128 = 0;
+
+    for item in budget.iter() {
+        if item.planned_amount <= 0 {
+            return Err(Error::InvalidBudget);
+        }
+        planned = add(planned, item.planned_amount)?;
+    }
+
+    // Line items cannot plan more than the milestone releases
+    if planned > tranche {
+        return Err(Error::InvalidBudget);
+    }
+
+    Ok(())
+}
+
+/// Check that every spend report points at a line item of the milestone budget
+pub(crate) fn validate_spend(
+    budget: &Vec<BudgetLineItem>,
+    spend: &Vec<SpendReport>,
+) -> Result<(), Error> {
+    for report in spend.iter() {
+        if report.line_item >= budget.len() || report.actual_amount < 0 {
+            return Err(Error::InvalidBudget);
+        }
+    }
+
+    Ok(())
+}
+
+fn milestone_variance(
+    env: &Env,
+    campaign_id: &String,
+    milestone: &Milestone,
+    threshold_bps: u32,
+) -> Result<MilestoneVariance, Error> {
+    let spend = match &milestone.proof_id {
+        Some(proof_id) => Some(storage::proof::get_proof(env, campaign_id, proof_id)?.spend),
+        None => None,
+    };
+
+    let mut variance = MilestoneVariance {
+        sequence: milestone.sequence,
+        reported: spend.is_some(),
+        planned_amount: 0,
+        actual_amount: 0,
+        variance: 0,
+        overrun: false,
+        line_items: Vec::new(env),
+    };
+
+    for (index, item) in milestone.budget.iter().enumerate() {
+        let line_item = index as u32;
+        let actual_amount = match &spend {
+            Some(spend) => spend
+                .iter()
+                .filter(|report| report.line_item == line_item)
+                .try_fold(0, |total, report| add(total, report.actual_amount))?,
+            None => 0,
+        };
+        let overrun =
+            spend.is_some() && exceeds(item.planned_amount, actual_amount, threshold_bps)?;
+
+        variance.planned_amount = add(variance.planned_amount, item.planned_amount)?;
+        variance.actual_amount = add(variance.actual_amount, actual_amount)?;
+        variance.line_items.push_back(LineItemVariance {
+            line_item,
+            category: item.category,
+            planned_amount: item.planned_amount,
+            actual_amount,
+            variance: match spend {
+                Some(_) => sub(actual_amount, item.planned_amount)?,
+                None => 0,
+            },
+            overrun,
+        });
+    }
+
+    if variance.reported {
+        variance.variance = sub(variance.actual_amount, variance.planned_amount)?;
+        variance.overrun = exceeds(
+            variance.planned_amount,
+            variance.actual_amount,
+            threshold_bps,
+        )?;
+    }
+
+    Ok(variance)
+}
+
+/// Whether `actual` is above `planned` plus `threshold_bps` of it
+fn exceeds(planned: i128, actual: i128, threshold_bps: u32) -> Result<bool, Error> {
+    let allowed = mul_div(
+        planned,
+        add(BPS_DENOMINATOR, i128::from(threshold_bps))?,
+        BPS_DENOMINATOR,
+    )?;
+
+    Ok(actual > allowed)
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{Env, String};
+
+use crate::{
+    events,
+    methods::{
+        reputation::record_campaign_cancelled, round::return_match,
+        withdraw_milestone::pay_withdrawal,
+    },
+    storage::{
+        campaign::{get_campaign, set_campaign},
+        stats::get_campaign_tally,
+        structs::campaign::CampaignStatus,
+        types::error::Error,
+    },
+};
+
+/// Cancel a campaign (Creator only)
+///
+/// Vesting tranches stop vesting: their unvested remainder is no longer owed
+/// to the creator. What the creator can withdraw at that point is paid out,
+/// and the rest stays in escrow for contributor refunds. The matched share of
+/// that escrow goes back to the matching rounds.
+pub fn cancel_campaign(env: &Env, campaign_id: String) -> Result<(), Error> {
+    let mut campaign = get_campaign(env, &campaign_id)?;
+
+    // Authorize the campaign creator
+    campaign.creator.require_auth();
+
+    if matches!(
+        campaign.status,
+        CampaignStatus::Rejected
+            | CampaignStatus::Cancelled
+            | CampaignStatus::Completed
+            | CampaignStatus::Frozen
+    ) {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    campaign.cancelled_at = Some(env.ledger().timestamp());
+
+    // Withdrawals need an approved campaign, so the creator is paid whatever
+    // can be withdrawn right now. Locked or paused tranches stay in escrow.
+    for sequence in (1..=campaign.current_milestone).rev() {
+        match pay_withdrawal(env, &mut campaign, sequence) {
+            Err(Error::ReleaseLocked) => continue,
+            Ok(_) | Err(Error::NoFundsToWithdraw | Error::WithdrawalsPaused) => break,
+            Err(error) => return Err(error),
+        }
+    }
+    campaign.withdrawable_amount = 0;
+
+    let escrowed = get_campaign_tally(env, &campaign_id).escrowed_amount;
+    return_match(env, &mut campaign, escrowed)?;
+
+    // Stop new donations; existing contributors can still refund
+    campaign.status = CampaignStatus::Cancelled;
+    set_campaign(env, &campaign_id, &campaign);
+
+    record_campaign_cancelled(env, &campaign.creator)?;
+    events::campaign::cancel_campaign(env, &campaign.creator, &campaign_id);
+
+    Ok(())
+}
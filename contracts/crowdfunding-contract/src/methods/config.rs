@@ -0,0 +1,127 @@
+use crate::events;
+use crate::storage::types::error::Error;
+use crate::storage::{self, structs::config::Config};
+use soroban_sdk::{Env, String, Vec};
+
+/// URIs and schemes are compared in fixed buffers, which bound their lengths
+const MAX_URI_LENGTH: u32 = 512;
+const MAX_SCHEME_LENGTH: u32 = 32;
+
+/// Set the maximum lengths of identifiers, titles, descriptions and URIs (Admin only)
+pub fn set_text_limits(
+    env: &Env,
+    max_id_length: u32,
+    max_title_length: u32,
+    max_description_length: u32,
+    max_uri_length: u32,
+) -> Result<(), Error> {
+    update_config(env, |config| {
+        if max_id_length == 0
+            || max_title_length == 0
+            || max_description_length == 0
+            || max_uri_length == 0
+            || max_uri_length > MAX_URI_LENGTH
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        config.max_id_length = max_id_length;
+        config.max_title_length = max_title_length;
+        config.max_description_length = max_description_length;
+        config.max_uri_length = max_uri_length;
+        Ok(())
+    })
+}
+
+/// Set the maximum number of milestones per campaign (Admin only)
+pub fn set_max_milestones(env: &Env, max_milestones: u32) -> Result<(), Error> {
+    update_config(env, |config| {
+        if max_milestones == 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        config.max_milestones = max_milestones;
+        Ok(())
+    })
+}
+
+/// Set the range campaign goals must fall in (Admin only)
+pub fn set_goal_bounds(env: &Env, min_goal: i128, max_goal: i128) -> Result<(), Error> {
+    update_config(env, |config| {
+        if min_goal <= 0 || min_goal > max_goal {
+            return Err(Error::InvalidConfig);
+        }
+
+        config.min_goal = min_goal;
+        config.max_goal = max_goal;
+        Ok(())
+    })
+}
+
+/// Set the URI schemes accepted for proofs, e.g. "ipfs://" (Admin only)
+pub fn set_allowed_uri_schemes(env: &Env, schemes: Vec<String>) -> Result<(), Error> {
+    update_config(env, |config| {
+        if schemes.is_empty()
+            || schemes
+                .iter()
+                .any(|scheme| scheme.is_empty() || scheme.len() > MAX_SCHEME_LENGTH)
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        config.allowed_uri_schemes = schemes.clone();
+        Ok(())
+    })
+}
+
+/// Get the platform limits on campaign, milestone and proof inputs
+pub fn get_config(env: &Env) -> Config {
+    storage::config::get_config(env)
+}
+
+/// Reject text longer than `max_length` bytes with `error`
+pub(crate) fn check_length(text: &String, max_length: u32, error: Error) -> Result<(), Error> {
+    if text.len() > max_length {
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Reject proof URIs that are too long or use a scheme outside the allow list
+pub(crate) fn check_uri(config: &Config, uri: &String) -> Result<(), Error> {
+    check_length(uri, config.max_uri_length, Error::UriTooLong)?;
+
+    let mut uri_buffer = [0u8; MAX_URI_LENGTH as usize];
+    let uri_bytes = &mut uri_buffer[..uri.len() as usize];
+    uri.copy_into_slice(uri_bytes);
+
+    let allowed = config.allowed_uri_schemes.iter().any(|scheme| {
+        let mut scheme_buffer = [0u8; MAX_SCHEME_LENGTH as usize];
+        let scheme_bytes = &mut scheme_buffer[..scheme.len() as usize];
+        scheme.copy_into_slice(scheme_bytes);
+
+        uri_bytes.starts_with(scheme_bytes)
+    });
+    if !allowed {
+        return Err(Error::UnsupportedUriScheme);
+    }
+
+    Ok(())
+}
+
+fn update_config(
+    env: &Env,
+    update: impl FnOnce(&mut Config) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let mut config = storage::config::get_config(env);
+    update(&mut config)?;
+
+    storage::config::set_config(env, &config);
+    events::config::config_updated(env, &config);
+
+    Ok(())
+}
@@ -1,9 +1,18 @@
 use crate::{
     events,
-    methods::token::token_transfer,
+    methods::{
+        contributor::record_listing,
+        escrow::receive,
+        math::{add, increment},
+        organization::record_raised,
+        portfolio::record_donation,
+        statement::record_giving,
+        stats::record_gift,
+    },
     storage::{
         campaign::{get_campaign, has_campaign, set_campaign},
-        contribution::set_contribution,
+        contribution::{add_contributor, get_contribution, has_contribution, set_contribution},
+        structs::campaign::{Campaign, CampaignStatus},
         types::error::Error,
     },
 };
@@ -14,35 +23,76 @@ pub fn contribute(
     contributor: Address,
     campaign_id: String,
     amount: i128,
+    anonymous: bool,
 ) -> Result<(), Error> {
     contributor.require_auth();
 
+    let mut campaign = check_contribution(env, &campaign_id, amount)?;
+
+    // Only what reaches escrow is credited
+    let received = receive(env, &contributor, amount)?;
+
+    // Repeat donors accumulate into a single contribution record
+    let is_new_supporter = !has_contribution(env, &campaign_id, &contributor);
+    let contributed = add(get_contribution(env, &campaign_id, &contributor), received)?;
+
+    campaign.total_raised = add(campaign.total_raised, received)?;
+    if is_new_supporter {
+        campaign.supporters = increment(campaign.supporters)?;
+    }
+
+    // The milestone plan donors contributed to can only change through an amendment
+    campaign.milestones_locked = true;
+
+    set_campaign(env, &campaign_id, &campaign);
+    record_raised(env, &campaign, received)?;
+    record_gift(env, &campaign_id, received)?;
+    record_donation(env, &campaign_id, &contributor, received)?;
+    record_giving(env, &campaign, &contributor, received)?;
+    set_contribution(env, &campaign_id, &contributor, contributed);
+    add_contributor(env, &campaign_id, &contributor);
+    record_listing(
+        env,
+        &campaign_id,
+        &contributor,
+        contributed,
+        is_new_supporter,
+        anonymous,
+    );
+    events::contribute::add_contribute(env, &contributor, &campaign_id, &received);
+
+    Ok(())
+}
+
+/// Check a contribution of `amount` against the campaign rules
+///
+/// Returns the campaign as it is before the contribution.
+pub(crate) fn check_contribution(
+    env: &Env,
+    campaign_id: &String,
+    amount: i128,
+) -> Result<Campaign, Error> {
     if amount <= 0 {
         return Err(Error::AmountMustBePositive);
     }
 
-    if !has_campaign(env, &campaign_id) {
+    if !has_campaign(env, campaign_id) {
         return Err(Error::CampaignNotFound);
     }
 
-    let mut campaign = get_campaign(env, &campaign_id)?;
+    let campaign = get_campaign(env, campaign_id)?;
+
+    if campaign.status != CampaignStatus::Approved {
+        return Err(Error::InvalidCampaignStatus);
+    }
 
     if campaign.min_donation > amount {
         return Err(Error::ContributionBelowMinimum);
     }
 
-    if campaign.total_raised + amount > campaign.goal {
+    if add(campaign.total_raised, amount)? > campaign.goal {
         return Err(Error::CampaignGoalExceeded);
     }
 
-    token_transfer(env, &contributor, &env.current_contract_address(), &amount)?;
-
-    campaign.total_raised += amount;
-    campaign.supporters += 1;
-
-    set_campaign(env, &campaign_id, &campaign);
-    set_contribution(env, &campaign_id, &contributor, amount);
-    events::contribute::add_contribute(env, &contributor, &campaign_id, &amount);
-
-    Ok(())
+    Ok(campaign)
 }
@@ -0,0 +1,141 @@
+use crate::storage::{self, structs::contributor::ContributorEntry};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Largest page returned by `list_contributors`
+const MAX_CONTRIBUTOR_PAGE: u32 = 50;
+
+/// Contributors shown on a campaign's leaderboard
+const LEADERBOARD_SIZE: u32 = 10;
+
+/// List a campaign's publicly listed contributors
+///
+/// Returns up to `limit` entries (at most 50) starting at `offset`.
+/// Contributors who opted out of public listing are left out. Entries follow
+/// the order of their first gift, except that when one leaves the list the
+/// latest listed contributor takes its place.
+pub fn list_contributors(
+    env: &Env,
+    campaign_id: &String,
+    offset: u32,
+    limit: u32,
+) -> Vec<ContributorEntry> {
+    let end = storage::contribution::get_public_contributor_count(env, campaign_id)
+        .min(offset.saturating_add(limit.min(MAX_CONTRIBUTOR_PAGE)));
+
+    let mut entries = Vec::new(env);
+    for index in offset..end {
+        let contributor = storage::contribution::get_public_contributor(env, campaign_id, index);
+        entries.push_back(ContributorEntry {
+            amount: storage::contribution::get_contribution(env, campaign_id, &contributor),
+            contributor,
+        });
+    }
+
+    entries
+}
+
+/// Get a campaign's top publicly listed contributors, largest first
+pub fn get_leaderboard(env: &Env, campaign_id: &String) -> Vec<ContributorEntry> {
+    storage::contribution::get_leaderboard(env, campaign_id)
+}
+
+/// Update the public listing after a contribution now totalling `amount`
+///
+/// Opting out hides the contributor from then on, including earlier gifts.
+pub(crate) fn record_listing(
+    env: &Env,
+    campaign_id: &String,
+    contributor: &Address,
+    amount: i128,
+    is_new_supporter: bool,
+    anonymous: bool,
+) {
+    if anonymous {
+        remove_listing(env, campaign_id, contributor);
+        return;
+    }
+
+    if is_new_supporter {
+        storage::contribution::add_public_contributor(env, campaign_id, contributor);
+    } else if !storage::contribution::is_public_contributor(env, campaign_id, contributor) {
+        // Contributors who opted out earlier stay hidden
+        return;
+    }
+
+    let mut leaderboard = storage::contribution::get_leaderboard(env, campaign_id);
+    if let Some(index) = find_entry(&leaderboard, contributor) {
+        leaderboard.remove(index);
+    }
+
+    // Entries are kept sorted by amount, earlier gifts first among equals
+    let position = leaderboard
+        .iter()
+        .position(|entry| entry.amount < amount)
+        .unwrap_or(leaderboard.len() as usize) as u32;
+    if position < LEADERBOARD_SIZE {
+        leaderboard.insert(
+            position,
+            ContributorEntry {
+                contributor: contributor.clone(),
+                amount,
+            },
+        );
+        if leaderboard.len() > LEADERBOARD_SIZE {
+            leaderboard.pop_back();
+        }
+    }
+    storage::contribution::set_leaderboard(env, campaign_id, &leaderboard);
+}
+
+/// Drop a contributor from the public listing and the leaderboard
+///
+/// A full leaderboard is refilled with the largest listed contributor not
+/// already on it.
+pub(crate) fn remove_listing(env: &Env, campaign_id: &String, contributor: &Address) {
+    storage::contribution::remove_public_contributor(env, campaign_id, contributor);
+
+    let mut leaderboard = storage::contribution::get_leaderboard(env, campaign_id);
+    let Some(index) = find_entry(&leaderboard, contributor) else {
+        return;
+    };
+    leaderboard.remove(index);
+
+    if leaderboard.len() + 1 == LEADERBOARD_SIZE {
+        if let Some(entry) = next_on_leaderboard(env, campaign_id, &leaderboard) {
+            leaderboard.push_back(entry);
+        }
+    }
+    storage::contribution::set_leaderboard(env, campaign_id, &leaderboard);
+}
+
+/// Largest listed contributor missing from `leaderboard`, earliest listed first among equals
+fn next_on_leaderboard(
+    env: &Env,
+    campaign_id: &String,
+    leaderboard: &Vec<ContributorEntry>,
+) -> Option<ContributorEntry> {
+    let mut next: Option<ContributorEntry> = None;
+    for index in 0..storage::contribution::get_public_contributor_count(env, campaign_id) {
+        let contributor = storage::contribution::get_public_contributor(env, campaign_id, index);
+        if find_entry(leaderboard, &contributor).is_some() {
+            continue;
+        }
+
+        let amount = storage::contribution::get_contribution(env, campaign_id, &contributor);
+        if next.as_ref().is_none_or(|entry| amount > entry.amount) {
+            next = Some(ContributorEntry {
+                contributor,
+                amount,
+            });
+        }
+    }
+
+    next
+}
+
+fn find_entry(leaderboard: &Vec<ContributorEntry>, contributor: &Address) -> Option<u32> {
+    leaderboard
+        .iter()
+        .position(|entry| entry.contributor == *contributor)
+        .map(|index| index as u32)
+}
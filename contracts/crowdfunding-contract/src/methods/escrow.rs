@@ -0,0 +1,126 @@
+use crate::events;
+use crate::methods::{
+    math::{add, sub},
+    stats::record_clawback,
+    token::token_transfer,
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{campaign::CampaignStatus, escrow::Reconciliation},
+};
+use soroban_sdk::{token, Address, Env, String};
+
+/// Compare the contract's token balance with what it owes
+///
+/// Liabilities are the escrow each campaign still holds, the matching pools
+/// of open rounds and the matched funds handed back to finalized rounds but
+/// not claimed yet. They are kept as a running total, so reconciling costs
+/// the same however many campaigns exist. A positive surplus is tokens sent
+/// to the contract directly; a negative one is a shortfall.
+pub fn reconcile(env: &Env) -> Result<Reconciliation, Error> {
+    let balance = contract_balance(env);
+    let liabilities = storage::escrow::get_liabilities(env);
+
+    Ok(Reconciliation {
+        balance,
+        liabilities,
+        surplus: sub(balance, liabilities)?,
+    })
+}
+
+/// Move tokens held above the contract's liabilities to `to` (Admin only)
+pub fn sweep_surplus(env: &Env, to: Address) -> Result<i128, Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let reconciliation = reconcile(env)?;
+    if reconciliation.surplus <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    token_transfer(
+        env,
+        &env.current_contract_address(),
+        &to,
+        &reconciliation.surplus,
+    )?;
+
+    events::escrow::surplus_swept(env, &to, reconciliation.surplus, reconciliation.liabilities);
+
+    Ok(reconciliation.surplus)
+}
+
+/// Charge escrow clawed back by the token issuer to a campaign (Admin only)
+///
+/// Clawback flags of an asset cannot be read on-chain, so the admin
+/// attributes each shortfall to the campaign whose escrow was taken. At most
+/// the current shortfall and the campaign's escrow are charged. Refunds and
+/// clawback claims then share what the campaign still holds, and the
+/// attributed amount is returned.
+pub fn attribute_clawback(env: &Env, campaign_id: String, amount: i128) -> Result<i128, Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::AmountMustBePositive);
+    }
+
+    let shortfall = -reconcile(env)?.surplus;
+    let escrowed = storage::stats::get_campaign_tally(env, &campaign_id).escrowed_amount;
+    let attributed = amount.min(shortfall).min(escrowed);
+    if attributed <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    record_clawback(env, &campaign_id, attributed)?;
+
+    // Contributors to a frozen campaign can only recover what is left
+    if storage::campaign::has_campaign(env, &campaign_id)
+        && storage::campaign::get_campaign(env, &campaign_id)?.status == CampaignStatus::Frozen
+    {
+        let mut finding = storage::fraud::get_fraud_finding(env, &campaign_id)?;
+        finding.recoverable_amount = sub(finding.recoverable_amount, attributed)?.max(0);
+        storage::fraud::set_fraud_finding(env, &campaign_id, &finding);
+    }
+
+    events::escrow::clawback_attributed(env, campaign_id, attributed);
+
+    Ok(attributed)
+}
+
+/// Take `amount` from `from` into escrow and return what actually arrived
+///
+/// Tokens that charge a fee on transfer deliver less than `amount`, so only
+/// the change in the contract's balance is credited.
+pub(crate) fn receive(env: &Env, from: &Address, amount: i128) -> Result<i128, Error> {
+    let before = contract_balance(env);
+    token_transfer(env, from, &env.current_contract_address(), &amount)?;
+    let received = sub(contract_balance(env), before)?;
+
+    if received <= 0 {
+        return Err(Error::AmountMustBePositive);
+    }
+
+    Ok(received)
+}
+
+/// Pay `amount` out of escrow to `to`
+pub(crate) fn pay_out(env: &Env, to: &Address, amount: i128) -> Result<(), Error> {
+    token_transfer(env, &env.current_contract_address(), to, &amount)
+}
+
+/// Move the running total of what the contract owes by `amount`
+///
+/// Campaign escrow enters it through the tally, round funds when they are
+/// deposited, handed back or claimed.
+pub(crate) fn adjust_liabilities(env: &Env, amount: i128) -> Result<(), Error> {
+    let liabilities = add(storage::escrow::get_liabilities(env), amount)?;
+    storage::escrow::set_liabilities(env, liabilities);
+    Ok(())
+}
+
+fn contract_balance(env: &Env) -> i128 {
+    let token_id = storage::token::get_token(env);
+    token::Client::new(env, &token_id).balance(&env.current_contract_address())
+}
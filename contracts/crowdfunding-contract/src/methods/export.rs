@@ -0,0 +1,61 @@
+use crate::methods::math::{add, sub};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::export::{CampaignExport, ContributionSummary},
+};
+use soroban_sdk::{Env, String, Vec};
+
+/// Layout version of `CampaignExport`
+const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Milestones and proofs returned per page
+const EXPORT_PAGE_SIZE: u32 = 10;
+
+/// Export a page of a campaign's complete record
+///
+/// `cursor` is 0 for the first page and the previous page's `next_cursor`
+/// afterwards.
+pub fn export_campaign(
+    env: &Env,
+    campaign_id: &String,
+    cursor: u32,
+) -> Result<CampaignExport, Error> {
+    let campaign = storage::campaign::get_campaign(env, campaign_id)?;
+    let proof_ids = storage::proof::get_campaign_proofs(env, campaign_id);
+    let records = campaign.milestones_count + proof_ids.len();
+    let end = records.min(cursor.saturating_add(EXPORT_PAGE_SIZE));
+
+    let mut released_amount = 0;
+    let mut milestones = Vec::new(env);
+    for sequence in 1..=campaign.milestones_count {
+        let milestone = storage::milestone::get_milestone(env, campaign_id, sequence)?;
+        released_amount = add(released_amount, milestone.claimed_amount)?;
+        if (cursor..end).contains(&(sequence - 1)) {
+            milestones.push_back(milestone);
+        }
+    }
+
+    let mut proofs = Vec::new(env);
+    for index in cursor.max(campaign.milestones_count)..end {
+        let proof_id = proof_ids.get_unchecked(index - campaign.milestones_count);
+        proofs.push_back(storage::proof::get_proof(env, campaign_id, &proof_id)?);
+    }
+
+    let contributions = ContributionSummary {
+        supporters: campaign.supporters,
+        contributed_amount: sub(campaign.total_raised, campaign.matched_amount)?,
+        matched_amount: campaign.matched_amount,
+        released_amount,
+        withdrawable_amount: campaign.withdrawable_amount,
+    };
+
+    Ok(CampaignExport {
+        format_version: EXPORT_FORMAT_VERSION,
+        campaign,
+        contributions,
+        milestones,
+        proofs,
+        next_cursor: (end < records).then_some(end),
+    })
+}
@@ -0,0 +1,139 @@
+use crate::events;
+use crate::methods::{
+    escrow::pay_out,
+    refund::{refundable_share, return_contribution},
+    round::return_match,
+    stats::record_release,
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{
+        campaign::{Campaign, CampaignStatus},
+        settlement::CampaignSettlement,
+    },
+};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Close a campaign whose milestones have all been validated and withdrawn
+///
+/// The matched share of the escrow left goes back to the matching rounds.
+/// The rest goes to the fallback beneficiary declared at creation, or, when
+/// there is none, stays in escrow for contributors to claim pro rata with
+/// `claim_leftover`. Anyone can finalize, so leftover funds never depend on
+/// the creator staying active.
+pub fn finalize_campaign(env: &Env, campaign_id: String) -> Result<CampaignSettlement, Error> {
+    let mut campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+
+    if campaign.status != CampaignStatus::Approved {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    if campaign.milestones_count == 0 || campaign.current_milestone < campaign.milestones_count {
+        return Err(Error::MilestoneNotCompleted);
+    }
+
+    // The last tranche must be withdrawn before the campaign can close
+    if campaign.withdrawable_amount > 0 {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    let escrowed = storage::stats::get_campaign_tally(env, &campaign_id).escrowed_amount;
+    return_match(env, &mut campaign, escrowed)?;
+
+    let tally = storage::stats::get_campaign_tally(env, &campaign_id);
+    let released_amount = tally.released_amount;
+    let leftover_amount = tally.escrowed_amount.max(0);
+
+    if leftover_amount > 0 {
+        if let Some(beneficiary) = &campaign.fallback_beneficiary {
+            pay_out(env, beneficiary, leftover_amount)?;
+            record_release(env, &campaign_id, leftover_amount)?;
+        }
+    }
+
+    campaign.status = CampaignStatus::Completed;
+    storage::campaign::set_campaign(env, &campaign_id, &campaign);
+
+    let settlement = record_settlement(
+        env,
+        &campaign,
+        released_amount,
+        leftover_amount,
+        campaign.fallback_beneficiary.clone(),
+    );
+
+    events::settlement::campaign_finalized(env, campaign_id, leftover_amount);
+
+    Ok(settlement)
+}
+
+/// Claim a pro-rata share of a finalized campaign's leftover escrow (Contributor only)
+///
+/// Each contributor claims once, and the last one receives what remains.
+pub fn claim_leftover(env: &Env, contributor: Address, campaign_id: String) -> Result<i128, Error> {
+    contributor.require_auth();
+
+    let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    if campaign.status != CampaignStatus::Completed {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    // The fallback beneficiary was already paid the whole leftover
+    let settlement = storage::settlement::get_settlement(env, &campaign_id)?;
+    if settlement.leftover_recipient.is_some() {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    if !storage::contribution::has_contribution(env, &campaign_id, &contributor) {
+        return Err(Error::ContributionNotFound);
+    }
+
+    let contribution = storage::contribution::get_contribution(env, &campaign_id, &contributor);
+    let amount = refundable_share(env, &campaign, contribution)?;
+    if amount <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    return_contribution(env, campaign, &contributor, contribution, amount)?;
+    events::settlement::leftover_returned(env, campaign_id, &contributor, amount);
+
+    Ok(amount)
+}
+
+/// Get the settlement summary of a finalized campaign
+pub fn get_settlement(env: &Env, campaign_id: &String) -> Result<CampaignSettlement, Error> {
+    storage::settlement::get_settlement(env, campaign_id)
+}
+
+/// Archive the settlement summary of a campaign that is being closed
+pub(crate) fn record_settlement(
+    env: &Env,
+    campaign: &Campaign,
+    released_amount: i128,
+    leftover_amount: i128,
+    leftover_recipient: Option<Address>,
+) -> CampaignSettlement {
+    let mut milestones = Vec::new(env);
+    for sequence in 1..=campaign.milestones_count {
+        milestones.push_back(sequence);
+    }
+
+    let settlement = CampaignSettlement {
+        campaign_id: campaign.id.clone(),
+        creator: campaign.creator.clone(),
+        payout_address: campaign.creator.clone(),
+        total_raised: campaign.total_raised,
+        supporters: campaign.supporters,
+        released_amount,
+        leftover_amount,
+        leftover_recipient,
+        created_at: campaign.created_at,
+        settled_at: env.ledger().timestamp(),
+        proof_ids: storage::proof::get_campaign_proofs(env, &campaign.id),
+        milestones,
+    };
+    storage::settlement::set_settlement(env, &campaign.id, &settlement);
+
+    settlement
+}
@@ -0,0 +1,141 @@
+use crate::events;
+use crate::methods::{
+    contributor::remove_listing,
+    escrow::pay_out,
+    math::{add, mul_div, sub},
+    organization::record_raised,
+    portfolio::record_donor_refund,
+    reputation::record_fraud_finding,
+    round::return_match,
+    statement::record_giving_refund,
+    stats::record_refund,
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{campaign::CampaignStatus, fraud::FraudFinding},
+};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+/// Freeze a fraudulent campaign and make its escrow recoverable (Admin only)
+///
+/// Every payout stops and all escrow not yet paid to the creator, validated
+/// tranches included, is split across contributors in proportion to their
+/// contributions, after the matched share goes back to the matching rounds. The finding is recorded publicly against the creator.
+pub fn freeze_campaign(
+    env: &Env,
+    campaign_id: String,
+    evidence_uri: String,
+    evidence_hash: BytesN<32>,
+) -> Result<FraudFinding, Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let mut campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+
+    if matches!(
+        campaign.status,
+        CampaignStatus::Rejected | CampaignStatus::Completed | CampaignStatus::Frozen
+    ) {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    // The matched share of the escrow goes back to the matching rounds
+    let escrowed = storage::stats::get_campaign_tally(env, &campaign_id).escrowed_amount;
+    return_match(env, &mut campaign, escrowed)?;
+
+    // Whatever else is still in escrow is recoverable: neither paid to the
+    // creator, refunded, nor clawed back by the token issuer
+    let recoverable_amount = storage::stats::get_campaign_tally(env, &campaign_id)
+        .escrowed_amount
+        .max(0);
+    let contributed_amount = sub(campaign.total_raised, campaign.matched_amount)?;
+
+    campaign.status = CampaignStatus::Frozen;
+    campaign.withdrawable_amount = 0;
+    storage::campaign::set_campaign(env, &campaign_id, &campaign);
+
+    let finding = FraudFinding {
+        campaign_id: campaign_id.clone(),
+        creator: campaign.creator.clone(),
+        evidence_uri: evidence_uri.clone(),
+        evidence_hash: evidence_hash.clone(),
+        recoverable_amount,
+        contributed_amount,
+        refunded_amount: 0,
+        found_at: env.ledger().timestamp(),
+    };
+    storage::fraud::set_fraud_finding(env, &campaign_id, &finding);
+    storage::fraud::add_creator_finding(env, &campaign.creator, &campaign_id);
+    record_fraud_finding(env, &campaign.creator)?;
+
+    events::fraud::campaign_frozen(
+        env,
+        campaign_id,
+        &campaign.creator,
+        evidence_uri,
+        evidence_hash,
+        recoverable_amount,
+    );
+
+    Ok(finding)
+}
+
+/// Claim a pro-rata share of a frozen campaign's recoverable escrow (Contributor only)
+pub fn claim_clawback(env: &Env, contributor: Address, campaign_id: String) -> Result<i128, Error> {
+    contributor.require_auth();
+
+    let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    if campaign.status != CampaignStatus::Frozen {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    if !storage::contribution::has_contribution(env, &campaign_id, &contributor) {
+        return Err(Error::ContributionNotFound);
+    }
+
+    let mut finding = storage::fraud::get_fraud_finding(env, &campaign_id)?;
+    let contribution = storage::contribution::get_contribution(env, &campaign_id, &contributor);
+    let share = clawback_share(&finding, contribution)?;
+
+    if share <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    // Each contributor claims once
+    storage::contribution::remove_contribution(env, &campaign_id, &contributor);
+    remove_listing(env, &campaign_id, &contributor);
+
+    finding.refunded_amount = add(finding.refunded_amount, share)?;
+    storage::fraud::set_fraud_finding(env, &campaign_id, &finding);
+    record_raised(env, &campaign, -share)?;
+    record_refund(env, &campaign_id, share)?;
+    record_donor_refund(env, &campaign_id, &contributor, share)?;
+    record_giving_refund(env, &campaign, &contributor, share)?;
+
+    pay_out(env, &contributor, share)?;
+    events::fraud::clawback_claimed(env, campaign_id, &contributor, share);
+
+    Ok(share)
+}
+
+/// Pro-rata share of a frozen campaign's recoverable escrow owed for `contribution`
+pub(crate) fn clawback_share(finding: &FraudFinding, contribution: i128) -> Result<i128, Error> {
+    mul_div(
+        contribution,
+        finding.recoverable_amount,
+        finding.contributed_amount,
+    )
+}
+
+/// Get the fraud finding recorded against a frozen campaign
+///
+/// Fails with `InvalidCampaignStatus` for a campaign that was never frozen.
+pub fn get_fraud_finding(env: &Env, campaign_id: &String) -> Result<FraudFinding, Error> {
+    storage::fraud::get_fraud_finding(env, campaign_id)
+}
+
+/// Get the ids of the campaigns frozen for fraud by a creator
+pub fn get_creator_fraud_findings(env: &Env, creator: &Address) -> Vec<String> {
+    storage::fraud::get_creator_findings(env, creator)
+}
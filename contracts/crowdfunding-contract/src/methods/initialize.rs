@@ -14,9 +14,9 @@ pub fn initialize(env: &Env, admin: Address, token: Address) -> Result<(), Error
         return Err(Error::ContractInitialized);
     }
 
-    set_admin(&env, &admin);
-    set_token(&env, &token);
-    events::contract::contract_initialized(&env, &admin, &token);
+    set_admin(env, &admin);
+    set_token(env, &token);
+    events::contract::contract_initialized(env, &admin, &token);
 
     Ok(())
 }
@@ -0,0 +1,102 @@
+use crate::methods::math::{add, mul_div, BPS_DENOMINATOR};
+use crate::storage::types::error::Error;
+use crate::storage::{self, structs::kpi::*};
+use soroban_sdk::{Env, String, Vec};
+
+/// Achieved-versus-target impact of a campaign
+///
+/// KPIs with the same name and unit are summed across milestones. Targets
+/// cover every milestone, while achieved values only count what was attested
+/// when a milestone was validated.
+pub fn get_campaign_impact(env: &Env, campaign_id: &String) -> Result<CampaignImpact, Error> {
+    let campaign = storage::campaign::get_campaign(env, campaign_id)?;
+    let mut kpis: Vec<KpiImpact> = Vec::new(env);
+
+    for sequence in 1..=campaign.milestones_count {
+        let milestone = storage::milestone::get_milestone(env, campaign_id, sequence)?;
+
+        for (index, kpi) in milestone.kpis.iter().enumerate() {
+            let achieved = milestone.kpi_values.get(index as u32).unwrap_or(0);
+
+            let position = kpis
+                .iter()
+                .position(|impact| impact.name == kpi.name && impact.unit == kpi.unit);
+            match position {
+                Some(position) => {
+                    let mut impact = kpis.get_unchecked(position as u32);
+                    impact.target = add(impact.target, kpi.target)?;
+                    impact.achieved = add(impact.achieved, achieved)?;
+                    kpis.set(position as u32, impact);
+                }
+                None => kpis.push_back(KpiImpact {
+                    name: kpi.name,
+                    unit: kpi.unit,
+                    target: kpi.target,
+                    achieved,
+                    progress_bps: 0,
+                }),
+            }
+        }
+    }
+
+    for index in 0..kpis.len() {
+        let mut impact = kpis.get_unchecked(index);
+        impact.progress_bps = progress_bps(impact.achieved, impact.target)?;
+        kpis.set(index, impact);
+    }
+
+    Ok(CampaignImpact {
+        campaign_id: campaign_id.clone(),
+        milestones_completed: campaign.current_milestone,
+        kpis,
+    })
+}
+
+/// Check the KPIs declared on a milestone
+pub(crate) fn validate_kpis(kpis: &Vec<Kpi>) -> Result<(), Error> {
+    for kpi in kpis.iter() {
+        if kpi.name.is_empty() || kpi.target <= 0 {
+            return Err(Error::InvalidKpi);
+        }
+    }
+
+    Ok(())
+}
+
+/// Turn the values reported in a proof into attested values, one per KPI
+///
+/// KPIs the proof does not report are attested as zero.
+pub(crate) fn attest_kpis(
+    env: &Env,
+    kpis: &Vec<Kpi>,
+    reports: &Vec<KpiReport>,
+) -> Result<Vec<i128>, Error> {
+    let mut values = Vec::new(env);
+    for _ in kpis.iter() {
+        values.push_back(0);
+    }
+
+    for (position, report) in reports.iter().enumerate() {
+        // Each KPI can be reported once, with a non-negative value
+        let duplicate = reports
+            .iter()
+            .take(position)
+            .any(|previous| previous.kpi == report.kpi);
+        if report.kpi >= kpis.len() || report.value < 0 || duplicate {
+            return Err(Error::InvalidKpi);
+        }
+        values.set(report.kpi, report.value);
+    }
+
+    Ok(values)
+}
+
+fn progress_bps(achieved: i128, target: i128) -> Result<u32, Error> {
+    if target <= 0 {
+        return Ok(0);
+    }
+
+    let progress = mul_div(achieved, BPS_DENOMINATOR, target)?;
+
+    Ok(progress.min(BPS_DENOMINATOR) as u32)
+}
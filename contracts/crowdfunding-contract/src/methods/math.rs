@@ -0,0 +1,39 @@
+use crate::storage::types::error::Error;
+
+/// Basis points in 100%
+pub(crate) const BPS_DENOMINATOR: i128 = 10_000;
+
+/// `a + b`, failing with `MathOverflow`
+pub(crate) fn add(a: i128, b: i128) -> Result<i128, Error> {
+    a.checked_add(b).ok_or(Error::MathOverflow)
+}
+
+/// `a - b`, failing with `MathUnderflow`
+pub(crate) fn sub(a: i128, b: i128) -> Result<i128, Error> {
+    a.checked_sub(b).ok_or(Error::MathUnderflow)
+}
+
+/// `a * b`, failing with `MathOverflow`
+pub(crate) fn mul(a: i128, b: i128) -> Result<i128, Error> {
+    a.checked_mul(b).ok_or(Error::MathOverflow)
+}
+
+/// `value * numerator / denominator`, rounded toward zero
+///
+/// Fails with `MathOverflow` when the product overflows or the denominator
+/// is zero.
+pub(crate) fn mul_div(value: i128, numerator: i128, denominator: i128) -> Result<i128, Error> {
+    mul(value, numerator)?
+        .checked_div(denominator)
+        .ok_or(Error::MathOverflow)
+}
+
+/// `count + 1`, failing with `MathOverflow`
+pub(crate) fn increment(count: u32) -> Result<u32, Error> {
+    count.checked_add(1).ok_or(Error::MathOverflow)
+}
+
+/// `count - 1`, failing with `MathUnderflow`
+pub(crate) fn decrement(count: u32) -> Result<u32, Error> {
+    count.checked_sub(1).ok_or(Error::MathUnderflow)
+}
@@ -1,6 +1,16 @@
 use crate::events;
+use crate::methods::{
+    budget::validate_budget,
+    config::check_length,
+    kpi::validate_kpis,
+    math::{add, increment, sub},
+    vesting::validate_vesting,
+};
 use crate::storage::types::error::Error;
-use crate::storage::{self, structs::milestone::*};
+use crate::storage::{
+    self,
+    structs::{budget::BudgetLineItem, campaign::Campaign, kpi::Kpi, milestone::*},
+};
 use soroban_sdk::{Env, String, Vec};
 
 /// Add a new milestone for a campaign (Creator only)
@@ -9,38 +19,62 @@ pub fn add_milestone(
     campaign_id: String,
     target_amount: i128,
     description: String,
+    due_date: Option<u64>,
+    budget: Vec<BudgetLineItem>,
+    kpis: Vec<Kpi>,
+    vesting: Vesting,
 ) -> Result<u32, Error> {
-    // Verify campaign exists and get it
-    let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
-
-    // Verify creator authorization
-    campaign.creator.require_auth();
+    // Verify campaign exists, creator authorization and that the plan is still editable
+    let campaign = get_editable_campaign(env, &campaign_id)?;
 
     // Validate target amount
     if target_amount <= 0 || target_amount > campaign.goal {
         return Err(Error::InvalidMilestoneAmount);
     }
 
-    // Get next sequence number
-    let sequence = campaign.milestones_count + 1;
+    // Get next sequence number, within the platform's milestone limit
+    let config = storage::config::get_config(env);
+    let sequence = increment(campaign.milestones_count)?;
+    if sequence > config.max_milestones {
+        return Err(Error::TooManyMilestones);
+    }
+    check_length(
+        &description,
+        config.max_description_length,
+        Error::DescriptionTooLong,
+    )?;
 
     // Validate sequential ordering (each milestone should be higher than previous)
+    let mut previous_target = 0;
     if sequence > 1 {
         let prev_milestone = get_milestone(env, &campaign_id, sequence - 1)?;
         if target_amount <= prev_milestone.target_amount {
             return Err(Error::MilestoneAmountNotIncreasing);
         }
+        previous_target = prev_milestone.target_amount;
     }
 
+    // Validate the budget against this milestone's tranche
+    validate_budget(&budget, sub(target_amount, previous_target)?)?;
+    validate_kpis(&kpis)?;
+    validate_vesting(&vesting)?;
+
     // Create milestone
     let milestone = Milestone {
         campaign_id: campaign_id.clone(),
         sequence,
         target_amount,
         description,
+        due_date,
+        budget,
+        kpis,
+        kpi_values: Vec::new(env),
         completed: false,
         proof_id: None,
         completed_at: None,
+        vesting,
+        claimed_amount: 0,
+        unlocks_at: None,
     };
 
     // Store milestone
@@ -75,3 +109,215 @@ pub fn get_campaign_milestones(env: &Env, campaign_id: &String) -> Result<Vec<Mi
 
     Ok(milestones)
 }
+
+/// Update a milestone while the plan is still editable (Creator only)
+pub fn update_milestone(
+    env: &Env,
+    campaign_id: String,
+    sequence: u32,
+    target_amount: i128,
+    description: String,
+    due_date: Option<u64>,
+    budget: Vec<BudgetLineItem>,
+    kpis: Vec<Kpi>,
+    vesting: Vesting,
+) -> Result<(), Error> {
+    let mut campaign = get_editable_campaign(env, &campaign_id)?;
+    let mut plan = get_plan(env, &campaign_id, &campaign, 1)?;
+
+    if sequence == 0 || sequence > plan.len() {
+        return Err(Error::MilestoneNotFound);
+    }
+
+    plan.set(
+        sequence - 1,
+        MilestoneDraft {
+            target_amount,
+            description,
+            due_date,
+            budget,
+            kpis,
+            vesting,
+        },
+    );
+    replace_plan(env, &campaign_id, &mut campaign, 1, &plan)?;
+
+    events::milestone::milestone_updated(env, campaign_id, sequence, target_amount);
+
+    Ok(())
+}
+
+/// Remove a milestone while the plan is still editable (Creator only)
+///
+/// Later milestones move down one sequence number.
+pub fn remove_milestone(env: &Env, campaign_id: String, sequence: u32) -> Result<(), Error> {
+    let mut campaign = get_editable_campaign(env, &campaign_id)?;
+    let mut plan = get_plan(env, &campaign_id, &campaign, 1)?;
+
+    if sequence == 0 || sequence > plan.len() {
+        return Err(Error::MilestoneNotFound);
+    }
+
+    plan.remove(sequence - 1);
+    replace_plan(env, &campaign_id, &mut campaign, 1, &plan)?;
+
+    events::milestone::milestone_removed(env, campaign_id, sequence);
+
+    Ok(())
+}
+
+/// Reorder milestones while the plan is still editable (Creator only)
+///
+/// `order` lists the current sequence numbers in their new order. Each
+/// milestone keeps its own tranche (its target minus the previous target), and
+/// cumulative targets are recomputed for the new order.
+pub fn reorder_milestones(env: &Env, campaign_id: String, order: Vec<u32>) -> Result<(), Error> {
+    let mut campaign = get_editable_campaign(env, &campaign_id)?;
+    let plan = get_plan(env, &campaign_id, &campaign, 1)?;
+
+    if order.len() != plan.len() {
+        return Err(Error::MilestoneNotInSequence);
+    }
+
+    let mut reordered = Vec::new(env);
+    let mut target_amount = 0;
+    for (position, sequence) in order.iter().enumerate() {
+        // Every sequence must appear exactly once
+        if sequence == 0
+            || sequence > plan.len()
+            || order.first_index_of(sequence) != Some(position as u32)
+        {
+            return Err(Error::MilestoneNotInSequence);
+        }
+
+        let draft = plan.get_unchecked(sequence - 1);
+        let previous_target = match sequence {
+            1 => 0,
+            _ => plan.get_unchecked(sequence - 2).target_amount,
+        };
+        target_amount = add(target_amount, sub(draft.target_amount, previous_target)?)?;
+
+        reordered.push_back(MilestoneDraft {
+            target_amount,
+            ..draft
+        });
+    }
+    replace_plan(env, &campaign_id, &mut campaign, 1, &reordered)?;
+
+    events::milestone::milestones_reordered(env, campaign_id, order);
+
+    Ok(())
+}
+
+/// Read milestones `from_sequence..` of a campaign as an editable plan
+pub(crate) fn get_plan(
+    env: &Env,
+    campaign_id: &String,
+    campaign: &Campaign,
+    from_sequence: u32,
+) -> Result<Vec<MilestoneDraft>, Error> {
+    let mut plan = Vec::new(env);
+
+    for sequence in from_sequence..=campaign.milestones_count {
+        let milestone = storage::milestone::get_milestone(env, campaign_id, sequence)?;
+        plan.push_back(MilestoneDraft {
+            target_amount: milestone.target_amount,
+            description: milestone.description,
+            due_date: milestone.due_date,
+            budget: milestone.budget,
+            kpis: milestone.kpis,
+            vesting: milestone.vesting,
+        });
+    }
+
+    Ok(plan)
+}
+
+/// Replace milestones `from_sequence..` of a campaign with `plan`
+///
+/// Targets must stay positive, within the goal and strictly increasing from
+/// the milestone right before `from_sequence`, and each budget must fit in
+/// its milestone's tranche. The new plan must respect the platform limits.
+pub(crate) fn replace_plan(
+    env: &Env,
+    campaign_id: &String,
+    campaign: &mut Campaign,
+    from_sequence: u32,
+    plan: &Vec<MilestoneDraft>,
+) -> Result<(), Error> {
+    // Validated milestones are part of the record and can never be replaced
+    if from_sequence <= campaign.current_milestone {
+        return Err(Error::MilestoneAlreadyCompleted);
+    }
+
+    let mut previous_target = match from_sequence {
+        1 => 0,
+        _ => storage::milestone::get_milestone(env, campaign_id, from_sequence - 1)?.target_amount,
+    };
+    let config = storage::config::get_config(env);
+    if from_sequence - 1 + plan.len() > config.max_milestones {
+        return Err(Error::TooManyMilestones);
+    }
+
+    for draft in plan.iter() {
+        check_length(
+            &draft.description,
+            config.max_description_length,
+            Error::DescriptionTooLong,
+        )?;
+        if draft.target_amount <= 0 || draft.target_amount > campaign.goal {
+            return Err(Error::InvalidMilestoneAmount);
+        }
+        if draft.target_amount <= previous_target {
+            return Err(Error::MilestoneAmountNotIncreasing);
+        }
+        validate_budget(&draft.budget, sub(draft.target_amount, previous_target)?)?;
+        validate_kpis(&draft.kpis)?;
+        validate_vesting(&draft.vesting)?;
+        previous_target = draft.target_amount;
+    }
+
+    for sequence in from_sequence..=campaign.milestones_count {
+        storage::milestone::remove_milestone(env, campaign_id, sequence);
+    }
+
+    let mut sequence = from_sequence;
+    for draft in plan.iter() {
+        let milestone = Milestone {
+            campaign_id: campaign_id.clone(),
+            sequence,
+            target_amount: draft.target_amount,
+            description: draft.description,
+            due_date: draft.due_date,
+            budget: draft.budget,
+            kpis: draft.kpis,
+            kpi_values: Vec::new(env),
+            completed: false,
+            proof_id: None,
+            completed_at: None,
+            vesting: draft.vesting,
+            claimed_amount: 0,
+            unlocks_at: None,
+        };
+        storage::milestone::set_milestone(env, campaign_id, sequence, &milestone);
+        sequence += 1;
+    }
+
+    campaign.milestones_count = sequence - 1;
+    storage::campaign::set_campaign(env, campaign_id, campaign);
+
+    Ok(())
+}
+
+/// Load a campaign for a direct plan edit by its creator
+pub(crate) fn get_editable_campaign(env: &Env, campaign_id: &String) -> Result<Campaign, Error> {
+    let campaign = storage::campaign::get_campaign(env, campaign_id)?;
+    campaign.creator.require_auth();
+
+    // Once donors have contributed, changes go through an amendment
+    if campaign.milestones_locked {
+        return Err(Error::MilestonePlanLocked);
+    }
+
+    Ok(campaign)
+}
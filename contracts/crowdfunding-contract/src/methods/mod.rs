@@ -1,12 +1,37 @@
 pub mod add_campaign;
 pub mod add_proof;
+pub mod amendment;
+pub mod audit;
+pub mod budget;
+pub mod cancel_campaign;
+pub mod config;
 pub mod contribute;
+pub mod contributor;
+pub mod escrow;
+pub mod export;
+pub mod finalize_campaign;
+pub mod fraud;
 pub mod get_campaign;
 pub mod get_proof;
 pub mod initialize;
+pub mod kpi;
+pub mod math;
 pub mod milestone;
+pub mod moderator;
+pub mod organization;
+pub mod portfolio;
+pub mod preview;
 pub mod proof_milestone;
 pub mod refund;
+pub mod release;
+pub mod report;
+pub mod reputation;
+pub mod review;
+pub mod revoke_proof;
+pub mod round;
+pub mod statement;
+pub mod stats;
 pub mod token;
+pub mod vesting;
 pub mod withdraw;
 pub mod withdraw_milestone;
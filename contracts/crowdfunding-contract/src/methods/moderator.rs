@@ -0,0 +1,39 @@
+use crate::events;
+use crate::storage::types::error::Error;
+use crate::storage::{self};
+use soroban_sdk::{Address, Env};
+
+/// Grant moderator rights to an address (Admin only)
+pub fn add_moderator(env: &Env, moderator: Address) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    storage::moderator::set_moderator(env, &moderator);
+    events::review::moderator_added(env, &moderator);
+
+    Ok(())
+}
+
+/// Revoke moderator rights from an address (Admin only)
+pub fn remove_moderator(env: &Env, moderator: Address) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    storage::moderator::remove_moderator(env, &moderator);
+    events::review::moderator_removed(env, &moderator);
+
+    Ok(())
+}
+
+/// Require the caller to be the admin or a registered moderator
+pub(crate) fn require_moderator(env: &Env, moderator: &Address) -> Result<(), Error> {
+    moderator.require_auth();
+
+    if *moderator != storage::admin::get_admin(env)
+        && !storage::moderator::is_moderator(env, moderator)
+    {
+        return Err(Error::Unauthorized);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use crate::events;
+use crate::methods::{math::add, moderator::require_moderator};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{campaign::Campaign, organization::*},
+};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+/// Register a new organisation controlled by the caller
+pub fn register_organization(
+    env: &Env,
+    controller: Address,
+    name: String,
+    registration_hash: BytesN<32>,
+    website: String,
+) -> Result<u32, Error> {
+    controller.require_auth();
+
+    if name.is_empty() {
+        return Err(Error::InvalidOrganization);
+    }
+
+    let organization_id = storage::organization::next_organization_id(env);
+    let organization = Organization {
+        id: organization_id,
+        name: name.clone(),
+        registration_hash,
+        website,
+        controllers: Vec::from_array(env, [controller]),
+        verification: VerificationLevel::Unverified,
+        total_raised: 0,
+        total_released: 0,
+        created_at: env.ledger().timestamp(),
+    };
+
+    storage::organization::set_organization(env, organization_id, &organization);
+    events::organization::organization_registered(env, organization_id, name);
+
+    Ok(organization_id)
+}
+
+/// Add a controlling address to an organisation (Controller only)
+pub fn add_organization_controller(
+    env: &Env,
+    controller: Address,
+    organization_id: u32,
+    new_controller: Address,
+) -> Result<(), Error> {
+    let mut organization = storage::organization::get_organization(env, organization_id)?;
+    require_controller(&organization, &controller)?;
+
+    if !organization.controllers.contains(&new_controller) {
+        organization.controllers.push_back(new_controller.clone());
+        storage::organization::set_organization(env, organization_id, &organization);
+    }
+
+    events::organization::controller_updated(env, organization_id, &new_controller, true);
+
+    Ok(())
+}
+
+/// Remove a controlling address from an organisation (Controller only)
+pub fn remove_organization_controller(
+    env: &Env,
+    controller: Address,
+    organization_id: u32,
+    removed_controller: Address,
+) -> Result<(), Error> {
+    let mut organization = storage::organization::get_organization(env, organization_id)?;
+    require_controller(&organization, &controller)?;
+
+    if let Some(index) = organization.controllers.first_index_of(&removed_controller) {
+        // An organisation must always keep at least one controller
+        if organization.controllers.len() == 1 {
+            return Err(Error::InvalidOrganization);
+        }
+
+        organization.controllers.remove(index);
+        storage::organization::set_organization(env, organization_id, &organization);
+    }
+
+    events::organization::controller_updated(env, organization_id, &removed_controller, false);
+
+    Ok(())
+}
+
+/// Set the verification level of an organisation (Moderator only)
+pub fn verify_organization(
+    env: &Env,
+    moderator: Address,
+    organization_id: u32,
+    level: VerificationLevel,
+) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+
+    let mut organization = storage::organization::get_organization(env, organization_id)?;
+    organization.verification = level;
+    storage::organization::set_organization(env, organization_id, &organization);
+
+    events::organization::organization_verified(env, organization_id, level);
+
+    Ok(())
+}
+
+/// Get organisation details, including its aggregate raised and released totals
+pub fn get_organization(env: &Env, organization_id: u32) -> Result<Organization, Error> {
+    storage::organization::get_organization(env, organization_id)
+}
+
+/// Get the ids of every campaign linked to an organisation
+pub fn get_organization_campaigns(env: &Env, organization_id: u32) -> Result<Vec<String>, Error> {
+    storage::organization::get_organization(env, organization_id)?;
+
+    Ok(storage::organization::get_organization_campaigns(
+        env,
+        organization_id,
+    ))
+}
+
+/// Link a new campaign to an organisation the creator controls
+pub(crate) fn link_campaign(
+    env: &Env,
+    organization_id: u32,
+    campaign_id: &String,
+    creator: &Address,
+) -> Result<(), Error> {
+    let organization = storage::organization::get_organization(env, organization_id)?;
+    if !organization.controllers.contains(creator) {
+        return Err(Error::Unauthorized);
+    }
+
+    storage::organization::add_organization_campaign(env, organization_id, campaign_id);
+
+    Ok(())
+}
+
+/// Add `amount` (negative on refunds) to the raised total of the campaign's organisation
+pub(crate) fn record_raised(env: &Env, campaign: &Campaign, amount: i128) -> Result<(), Error> {
+    if let Some(organization_id) = campaign.organization_id {
+        let mut organization = storage::organization::get_organization(env, organization_id)?;
+        organization.total_raised = add(organization.total_raised, amount)?;
+        storage::organization::set_organization(env, organization_id, &organization);
+    }
+
+    Ok(())
+}
+
+/// Add `amount` to the released total of the campaign's organisation
+pub(crate) fn record_released(env: &Env, campaign: &Campaign, amount: i128) -> Result<(), Error> {
+    if let Some(organization_id) = campaign.organization_id {
+        let mut organization = storage::organization::get_organization(env, organization_id)?;
+        organization.total_released = add(organization.total_released, amount)?;
+        storage::organization::set_organization(env, organization_id, &organization);
+    }
+
+    Ok(())
+}
+
+fn require_controller(organization: &Organization, controller: &Address) -> Result<(), Error> {
+    controller.require_auth();
+
+    if !organization.controllers.contains(controller) {
+        return Err(Error::Unauthorized);
+    }
+
+    Ok(())
+}
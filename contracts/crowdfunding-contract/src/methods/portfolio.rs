@@ -0,0 +1,118 @@
+use crate::methods::{
+    fraud::clawback_share,
+    math::add,
+    refund::{is_refundable, refundable_share},
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{
+        campaign::{Campaign, CampaignStatus},
+        portfolio::{DonorPosition, PortfolioEntry},
+    },
+};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Largest page returned by `get_donor_portfolio`
+const MAX_PORTFOLIO_PAGE: u32 = 50;
+
+/// List the campaigns a donor has supported, oldest first
+///
+/// Returns up to `limit` entries (at most 50) starting at `offset`.
+pub fn get_donor_portfolio(
+    env: &Env,
+    donor: &Address,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<PortfolioEntry>, Error> {
+    let campaign_ids = storage::portfolio::get_donor_campaigns(env, donor);
+    let end = campaign_ids
+        .len()
+        .min(offset.saturating_add(limit.min(MAX_PORTFOLIO_PAGE)));
+
+    let mut entries = Vec::new(env);
+    for index in offset..end {
+        let campaign_id = campaign_ids.get_unchecked(index);
+        let position = storage::portfolio::get_donor_position(env, &campaign_id, donor);
+
+        // Archived campaigns have paid out everything they held
+        let (status, claimable) = if storage::campaign::has_campaign(env, &campaign_id) {
+            let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+            (campaign.status, claimable_amount(env, &campaign, donor)?)
+        } else {
+            (CampaignStatus::Completed, 0)
+        };
+
+        entries.push_back(PortfolioEntry {
+            campaign_id,
+            status,
+            contributed: position.contributed,
+            refunded: position.refunded,
+            claimable,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Record a gift in the donor's portfolio
+pub(crate) fn record_donation(
+    env: &Env,
+    campaign_id: &String,
+    donor: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    storage::portfolio::add_donor_campaign(env, donor, campaign_id);
+    update_position(env, campaign_id, donor, |position| {
+        position.contributed = add(position.contributed, amount)?;
+        Ok(())
+    })
+}
+
+/// Record funds returned to the donor from a campaign's escrow
+pub(crate) fn record_donor_refund(
+    env: &Env,
+    campaign_id: &String,
+    donor: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    update_position(env, campaign_id, donor, |position| {
+        position.refunded = add(position.refunded, amount)?;
+        Ok(())
+    })
+}
+
+fn update_position<F>(
+    env: &Env,
+    campaign_id: &String,
+    donor: &Address,
+    update: F,
+) -> Result<(), Error>
+where
+    F: FnOnce(&mut DonorPosition) -> Result<(), Error>,
+{
+    let mut position = storage::portfolio::get_donor_position(env, campaign_id, donor);
+    update(&mut position)?;
+    storage::portfolio::set_donor_position(env, campaign_id, donor, &position);
+    Ok(())
+}
+
+/// What `refund`, `claim_leftover` or `claim_clawback` would pay the donor right now
+fn claimable_amount(env: &Env, campaign: &Campaign, donor: &Address) -> Result<i128, Error> {
+    let campaign_id = &campaign.id;
+    if !storage::contribution::has_contribution(env, campaign_id, donor) {
+        return Ok(0);
+    }
+
+    let contribution = storage::contribution::get_contribution(env, campaign_id, donor);
+    match campaign.status {
+        CampaignStatus::Frozen => {
+            let finding = storage::fraud::get_fraud_finding(env, campaign_id)?;
+            clawback_share(&finding, contribution)
+        }
+        // A fallback beneficiary leaves no escrow to share
+        CampaignStatus::Completed => refundable_share(env, campaign, contribution),
+        status if is_refundable(status) => refundable_share(env, campaign, contribution),
+        _ => Ok(0),
+    }
+}
@@ -0,0 +1,72 @@
+use crate::methods::{
+    contribute::check_contribution,
+    math::{add, decrement, sub},
+    refund::check_refund,
+    withdraw_milestone::plan_withdrawal,
+};
+use crate::storage::{
+    self,
+    structs::preview::{ContributePreview, RefundPreview, WithdrawPreview},
+    types::error::Error,
+};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Preview a contribution of `amount` without making it
+///
+/// Fails with the error `contribute` would raise. The contract charges no
+/// fee, so the whole amount is credited, but tokens that take a fee on
+/// transfer deliver less than the previewed amount and cannot be read ahead.
+pub fn preview_contribute(
+    env: &Env,
+    campaign_id: &String,
+    amount: i128,
+) -> Result<ContributePreview, Error> {
+    let campaign = check_contribution(env, campaign_id, amount)?;
+    let total_raised = add(campaign.total_raised, amount)?;
+
+    Ok(ContributePreview {
+        amount,
+        total_raised,
+        remaining_goal: sub(campaign.goal, total_raised)?,
+    })
+}
+
+/// Preview the refund a contributor would get now
+///
+/// Fails with the error `refund` would raise.
+pub fn preview_refund(
+    env: &Env,
+    contributor: &Address,
+    campaign_id: &String,
+) -> Result<RefundPreview, Error> {
+    let (campaign, contribution, amount) = check_refund(env, contributor, campaign_id)?;
+
+    Ok(RefundPreview {
+        amount,
+        total_raised: sub(campaign.total_raised, contribution)?,
+        supporters: decrement(campaign.supporters)?,
+    })
+}
+
+/// Preview what a milestone withdrawal would pay the creator now
+///
+/// Fails with the error `withdraw_milestone_funds` would raise.
+pub fn preview_withdraw(
+    env: &Env,
+    campaign_id: &String,
+    milestone_sequence: u32,
+) -> Result<WithdrawPreview, Error> {
+    let campaign = storage::campaign::get_campaign(env, campaign_id)?;
+    let (amount, claimed) = plan_withdrawal(env, &campaign, milestone_sequence)?;
+
+    let mut milestones = Vec::new(env);
+    for milestone in claimed.iter() {
+        milestones.push_back(milestone.sequence);
+    }
+
+    Ok(WithdrawPreview {
+        amount,
+        withdrawable_amount: sub(campaign.withdrawable_amount, amount)?,
+        milestones,
+    })
+}
@@ -1,7 +1,23 @@
 use crate::events;
+use crate::methods::reputation::{
+    record_milestone_completed, record_milestone_rejected, record_milestone_rolled_back,
+};
+use crate::methods::{
+    audit::check_auditor_veto,
+    budget::validate_spend,
+    kpi::attest_kpis,
+    math::{add, sub},
+    vesting::released_amount,
+};
 use crate::storage;
-use crate::storage::types::error::Error;
-use soroban_sdk::{Env, String};
+use crate::storage::{
+    structs::{
+        campaign::{Campaign, CampaignStatus},
+        milestone::Milestone,
+    },
+    types::error::Error,
+};
+use soroban_sdk::{Env, String, Vec};
 
 /// Validate a milestone with proof (Admin only)
 pub fn validate_milestone_with_proof(
@@ -18,8 +34,19 @@ pub fn validate_milestone_with_proof(
     let mut campaign = storage::campaign::get_campaign(env, &campaign_id)?;
     let mut milestone = storage::milestone::get_milestone(env, &campaign_id, milestone_sequence)?;
 
-    // Verify proof exists
-    let _proof = storage::proof::get_proof(env, &campaign_id, &proof_id)?;
+    // Only a live campaign can release new tranches
+    if campaign.status != CampaignStatus::Approved {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    // Verify proof exists and its spend matches the milestone budget
+    let proof = storage::proof::get_proof(env, &campaign_id, &proof_id)?;
+    if proof.revoked_at.is_some() {
+        return Err(Error::ProofRevoked);
+    }
+    validate_spend(&milestone.budget, &proof.spend)?;
+    let kpi_values = attest_kpis(env, &milestone.kpis, &proof.kpi_values)?;
+    check_auditor_veto(env, &campaign_id, &proof_id)?;
 
     // Check if milestone can be completed
     if milestone.completed {
@@ -37,20 +64,114 @@ pub fn validate_milestone_with_proof(
     }
 
     // Complete milestone
+    let completed_at = env.ledger().timestamp();
     milestone.completed = true;
     milestone.proof_id = Some(proof_id.clone());
-    milestone.completed_at = Some(env.ledger().timestamp());
+    milestone.completed_at = Some(completed_at);
+    milestone.kpi_values = kpi_values;
+    milestone.unlocks_at = Some(
+        completed_at
+            .checked_add(campaign.release_delay)
+            .ok_or(Error::MathOverflow)?,
+    );
 
-    // Update campaign
+    // Release this milestone's tranche (its target minus the previous target)
+    let previous_target = match milestone_sequence {
+        1 => 0,
+        _ => {
+            storage::milestone::get_milestone(env, &campaign_id, milestone_sequence - 1)?
+                .target_amount
+        }
+    };
     campaign.current_milestone = milestone_sequence;
-    campaign.withdrawable_amount = milestone.target_amount;
+    campaign.withdrawable_amount = add(
+        campaign.withdrawable_amount,
+        sub(milestone.target_amount, previous_target)?,
+    )?;
 
     // Store updates
     storage::milestone::set_milestone(env, &campaign_id, milestone_sequence, &milestone);
     storage::campaign::set_campaign(env, &campaign_id, &campaign);
 
-    // Emit event
+    // Track delivery against the milestone's due date
+    record_milestone_completed(env, &campaign.creator, is_on_time(&milestone, completed_at))?;
+
+    // Emit events
+    events::proof::proof_validated(env, &campaign_id, &proof_id);
     events::milestone::milestone_completed(env, campaign_id, milestone_sequence, proof_id);
 
     Ok(())
 }
+
+/// Reject the proof submitted for a milestone (Admin only)
+pub fn reject_milestone(
+    env: &Env,
+    campaign_id: String,
+    milestone_sequence: u32,
+    reason: String,
+) -> Result<(), Error> {
+    // Admin authorization
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    let milestone = storage::milestone::get_milestone(env, &campaign_id, milestone_sequence)?;
+
+    if milestone.completed {
+        return Err(Error::MilestoneAlreadyCompleted);
+    }
+
+    record_milestone_rejected(env, &campaign.creator)?;
+
+    events::milestone::milestone_rejected(env, campaign_id, milestone_sequence, reason);
+
+    Ok(())
+}
+
+/// Undo the validation of the campaign's latest completed milestone
+///
+/// Its tranche goes back to escrow and the creator's reputation trades the
+/// milestone's on-time or late credit for a rejection. The caller stores the
+/// updated campaign.
+pub(crate) fn roll_back_milestone(
+    env: &Env,
+    campaign: &mut Campaign,
+    mut milestone: Milestone,
+) -> Result<(), Error> {
+    let previous_target = match milestone.sequence {
+        1 => 0,
+        _ => {
+            storage::milestone::get_milestone(env, &campaign.id, milestone.sequence - 1)?
+                .target_amount
+        }
+    };
+    // Cancellation already returned the unvested part of the tranche
+    let tranche = sub(milestone.target_amount, previous_target)?;
+    let owed = match campaign.cancelled_at {
+        Some(cancelled_at) => released_amount(&milestone, tranche, cancelled_at)?,
+        None => tranche,
+    };
+    campaign.withdrawable_amount = sub(campaign.withdrawable_amount, owed)?;
+    campaign.current_milestone = milestone.sequence - 1;
+
+    let on_time = milestone
+        .completed_at
+        .is_some_and(|completed_at| is_on_time(&milestone, completed_at));
+    milestone.completed = false;
+    milestone.proof_id = None;
+    milestone.completed_at = None;
+    milestone.kpi_values = Vec::new(env);
+    milestone.unlocks_at = None;
+    storage::milestone::set_milestone(env, &campaign.id, milestone.sequence, &milestone);
+
+    record_milestone_rolled_back(env, &campaign.creator, on_time)?;
+
+    Ok(())
+}
+
+/// Whether a milestone completed at `completed_at` met its due date
+fn is_on_time(milestone: &Milestone, completed_at: u64) -> bool {
+    milestone
+        .due_date
+        .is_none_or(|due_date| completed_at <= due_date)
+}
@@ -1,9 +1,22 @@
 use crate::{
     events,
-    methods::token::token_transfer,
+    methods::{
+        contributor::remove_listing,
+        escrow::pay_out,
+        math::{decrement, mul_div, sub},
+        organization::record_raised,
+        portfolio::record_donor_refund,
+        report::withdraw_report_weight,
+        statement::record_giving_refund,
+        stats::record_refund,
+    },
     storage::{
+        self,
         campaign::{get_campaign, set_campaign},
-        contribution::{get_contribution, has_contribution, remove_contribution},
+        contribution::{
+            get_contribution, has_contribution, remove_contribution, remove_contributor,
+        },
+        structs::campaign::{Campaign, CampaignStatus},
         types::error::Error,
     },
 };
@@ -12,21 +25,96 @@ use soroban_sdk::{Address, Env, String};
 pub fn refund(env: &Env, contributor: Address, campaign_id: String) -> Result<(), Error> {
     contributor.require_auth();
 
-    if !has_contribution(env, &campaign_id, &contributor) {
+    let (campaign, contribution, amount) = check_refund(env, &contributor, &campaign_id)?;
+    return_contribution(env, campaign, &contributor, contribution, amount)?;
+    events::refund::refund(env, &contributor, &campaign_id, &amount);
+
+    Ok(())
+}
+
+/// Pay `amount` back for a contributor's whole `contribution` and remove it
+/// from the campaign
+pub(crate) fn return_contribution(
+    env: &Env,
+    mut campaign: Campaign,
+    contributor: &Address,
+    contribution: i128,
+    amount: i128,
+) -> Result<(), Error> {
+    let campaign_id = &campaign.id;
+
+    pay_out(env, contributor, amount)?;
+
+    // The whole contribution leaves the total raised, even when only part of
+    // it could be paid back. Later payouts are worked out from the escrow.
+    campaign.total_raised = sub(campaign.total_raised, contribution)?;
+    campaign.supporters = decrement(campaign.supporters)?;
+
+    remove_contribution(env, campaign_id, contributor);
+    remove_contributor(env, campaign_id, contributor);
+    remove_listing(env, campaign_id, contributor);
+    withdraw_report_weight(env, campaign_id, contributor)?;
+    set_campaign(env, campaign_id, &campaign);
+    record_raised(env, &campaign, -contribution)?;
+    record_refund(env, campaign_id, amount)?;
+    record_donor_refund(env, campaign_id, contributor, amount)?;
+    record_giving_refund(env, &campaign, contributor, amount)?;
+
+    Ok(())
+}
+
+/// Check a refund and return the campaign, the contribution and the amount
+/// the refund would pay
+pub(crate) fn check_refund(
+    env: &Env,
+    contributor: &Address,
+    campaign_id: &String,
+) -> Result<(Campaign, i128, i128), Error> {
+    if !has_contribution(env, campaign_id, contributor) {
         return Err(Error::ContributionNotFound);
     }
 
-    let mut campaign = get_campaign(env, &campaign_id)?;
-    let amount = get_contribution(env, &campaign_id, &contributor);
+    let campaign = get_campaign(env, campaign_id)?;
 
-    token_transfer(env, &env.current_contract_address(), &contributor, &amount)?;
+    if !is_refundable(campaign.status) {
+        return Err(Error::InvalidCampaignStatus);
+    }
 
-    campaign.total_raised -= amount;
-    campaign.supporters -= 1;
+    let contribution = get_contribution(env, campaign_id, contributor);
+    let amount = refundable_share(env, &campaign, contribution)?;
+    if amount <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
 
-    remove_contribution(env, &campaign_id, &contributor);
-    set_campaign(env, &campaign_id, &campaign);
-    events::refund::refund(env, &contributor, &campaign_id, &amount);
+    Ok((campaign, contribution, amount))
+}
 
-    Ok(())
+/// Part of `contribution` the campaign's escrow can still pay back
+///
+/// Tranches already released to the creator, or vested and still owed to
+/// them, are not refundable. Once any has been, every contributor gets the
+/// same fraction of their contribution back from what remains.
+pub(crate) fn refundable_share(
+    env: &Env,
+    campaign: &Campaign,
+    contribution: i128,
+) -> Result<i128, Error> {
+    let escrowed = storage::stats::get_campaign_tally(env, &campaign.id).escrowed_amount;
+    let refundable = sub(escrowed, campaign.withdrawable_amount)?;
+
+    // Matching funds are not owed to contributors
+    let outstanding = sub(campaign.total_raised, campaign.matched_amount)?;
+    if refundable >= outstanding {
+        return Ok(contribution);
+    }
+
+    mul_div(contribution, refundable.max(0), outstanding)
+}
+
+/// Whether contributions to a campaign in `status` can still be refunded
+///
+/// Leftover escrow of a completed campaign has already been settled, and a
+/// frozen campaign's escrow is only recoverable pro rata.
+pub(crate) fn is_refundable(status: CampaignStatus) -> bool {
+    !matches!(status, CampaignStatus::Completed | CampaignStatus::Frozen)
 }
@@ -0,0 +1,62 @@
+use crate::events;
+use crate::methods::{milestone::get_editable_campaign, proof_milestone::roll_back_milestone};
+use crate::storage;
+use crate::storage::types::error::Error;
+use soroban_sdk::{Env, String};
+
+/// Set the delay between milestone validation and payout (Creator only)
+///
+/// Like the milestone plan, the delay can only change before the first
+/// contribution, so donors always contribute under known release terms.
+pub fn set_release_delay(env: &Env, campaign_id: String, release_delay: u64) -> Result<(), Error> {
+    let mut campaign = get_editable_campaign(env, &campaign_id)?;
+
+    campaign.release_delay = release_delay;
+    storage::campaign::set_campaign(env, &campaign_id, &campaign);
+
+    events::milestone::release_delay_set(env, campaign_id, release_delay);
+
+    Ok(())
+}
+
+/// Cancel the pending release of the latest validated milestone (Admin only)
+///
+/// Only possible while the release delay is running. The milestone goes back
+/// to uncompleted, its tranche returns to escrow and the creator's reputation
+/// records a rejected milestone.
+pub fn cancel_milestone_release(
+    env: &Env,
+    campaign_id: String,
+    milestone_sequence: u32,
+    reason: String,
+) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let mut campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    let milestone = storage::milestone::get_milestone(env, &campaign_id, milestone_sequence)?;
+
+    if !milestone.completed {
+        return Err(Error::MilestoneNotCompleted);
+    }
+
+    // Later validations build on this one and must be cancelled first
+    if milestone_sequence != campaign.current_milestone {
+        return Err(Error::MilestoneNotInSequence);
+    }
+
+    // Once unlocked the release is final
+    let pending = milestone
+        .unlocks_at
+        .is_some_and(|unlocks_at| env.ledger().timestamp() < unlocks_at);
+    if !pending {
+        return Err(Error::MilestoneAlreadyCompleted);
+    }
+
+    roll_back_milestone(env, &mut campaign, milestone)?;
+    storage::campaign::set_campaign(env, &campaign_id, &campaign);
+
+    events::milestone::release_cancelled(env, campaign_id, milestone_sequence, reason);
+
+    Ok(())
+}
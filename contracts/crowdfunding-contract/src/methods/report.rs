@@ -0,0 +1,220 @@
+use crate::events;
+use crate::methods::{
+    math::{add, mul, sub, BPS_DENOMINATOR},
+    moderator::require_moderator,
+    reputation::record_challenge_upheld,
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{
+        campaign::CampaignStatus,
+        report::{CampaignReports, FraudReport, ReportCategory, ReportStatus},
+    },
+};
+use soroban_sdk::{Address, Env, String, Vec};
+
+const MAX_THRESHOLD_BPS: u32 = 10_000;
+
+/// Report concerns about a campaign (Contributor only)
+///
+/// Each contributor can report a campaign once, weighted by their
+/// contribution. Once the reports filed since the last moderator decision
+/// weigh at least the report threshold of the donations, withdrawals are
+/// paused and the campaign joins the moderator review queue.
+pub fn report_campaign(
+    env: &Env,
+    reporter: Address,
+    campaign_id: String,
+    category: ReportCategory,
+    evidence_uri: String,
+) -> Result<(), Error> {
+    reporter.require_auth();
+
+    let mut campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    if matches!(
+        campaign.status,
+        CampaignStatus::Completed | CampaignStatus::Frozen
+    ) {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    if !storage::contribution::has_contribution(env, &campaign_id, &reporter) {
+        return Err(Error::ContributionNotFound);
+    }
+
+    let mut reports = storage::report::get_campaign_reports(env, &campaign_id);
+    if reports
+        .reports
+        .iter()
+        .any(|report| report.reporter == reporter)
+    {
+        return Err(Error::ReportAlreadyFiled);
+    }
+
+    let weight = storage::contribution::get_contribution(env, &campaign_id, &reporter);
+    reports.reports.push_back(FraudReport {
+        reporter: reporter.clone(),
+        category,
+        evidence_uri,
+        weight,
+        reported_at: env.ledger().timestamp(),
+    });
+    reports.pending_weight = add(reports.pending_weight, weight)?;
+
+    events::report::campaign_reported(env, &campaign_id, &reporter, category, weight);
+
+    let contributed = sub(campaign.total_raised, campaign.matched_amount)?;
+    let threshold = mul(
+        contributed,
+        i128::from(storage::report::get_report_threshold(env)),
+    )?;
+    let reported = mul(reports.pending_weight, BPS_DENOMINATOR)?;
+
+    if !matches!(
+        reports.status,
+        ReportStatus::UnderReview | ReportStatus::Upheld
+    ) && reported >= threshold
+    {
+        reports.status = ReportStatus::UnderReview;
+        campaign.withdrawals_paused = true;
+        storage::campaign::set_campaign(env, &campaign_id, &campaign);
+        storage::report::enqueue_report_review(env, &campaign_id);
+
+        events::report::review_triggered(env, &campaign_id, reports.pending_weight);
+    }
+
+    storage::report::set_campaign_reports(env, &campaign_id, &reports);
+
+    Ok(())
+}
+
+/// Dismiss the reports under review and resume withdrawals (Moderator only)
+pub fn dismiss_reports(
+    env: &Env,
+    moderator: Address,
+    campaign_id: String,
+    reason: String,
+) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+
+    resolve_reports(
+        env,
+        &moderator,
+        &campaign_id,
+        ReportStatus::Dismissed,
+        reason,
+    )
+}
+
+/// Uphold the reports under review, keeping withdrawals paused (Moderator only)
+///
+/// The creator's reputation records an upheld challenge. The admin can then
+/// freeze the campaign to claw back its escrow.
+pub fn uphold_reports(
+    env: &Env,
+    moderator: Address,
+    campaign_id: String,
+    reason: String,
+) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+
+    resolve_reports(env, &moderator, &campaign_id, ReportStatus::Upheld, reason)
+}
+
+/// Set the share of donations, in basis points, whose reports trigger a review (Admin only)
+pub fn set_report_threshold(env: &Env, threshold_bps: u32) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    if threshold_bps == 0 || threshold_bps > MAX_THRESHOLD_BPS {
+        return Err(Error::InvalidReportThreshold);
+    }
+
+    storage::report::set_report_threshold(env, threshold_bps);
+    events::report::threshold_set(env, threshold_bps);
+
+    Ok(())
+}
+
+/// Drop the weight of a contributor's report when they are refunded
+///
+/// A refunded contributor no longer has funds at stake, so a report still
+/// waiting for a decision stops counting towards the threshold.
+pub(crate) fn withdraw_report_weight(
+    env: &Env,
+    campaign_id: &String,
+    reporter: &Address,
+) -> Result<(), Error> {
+    let mut reports = storage::report::get_campaign_reports(env, campaign_id);
+
+    let Some(index) = reports
+        .reports
+        .iter()
+        .position(|report| report.reporter == *reporter)
+    else {
+        return Ok(());
+    };
+    let index = index as u32;
+    let mut report = reports.reports.get_unchecked(index);
+
+    if index >= reports.pending_from {
+        reports.pending_weight = sub(reports.pending_weight, report.weight)?;
+    }
+    report.weight = 0;
+    reports.reports.set(index, report);
+    storage::report::set_campaign_reports(env, campaign_id, &reports);
+
+    Ok(())
+}
+
+/// Get the reports filed against a campaign and their outcome
+pub fn get_campaign_reports(env: &Env, campaign_id: &String) -> CampaignReports {
+    storage::report::get_campaign_reports(env, campaign_id)
+}
+
+/// Get the campaigns waiting for a moderator decision on their reports
+pub fn get_report_queue(env: &Env) -> Vec<String> {
+    storage::report::get_report_queue(env)
+}
+
+/// Get the current report threshold in basis points
+pub fn get_report_threshold(env: &Env) -> u32 {
+    storage::report::get_report_threshold(env)
+}
+
+fn resolve_reports(
+    env: &Env,
+    moderator: &Address,
+    campaign_id: &String,
+    status: ReportStatus,
+    reason: String,
+) -> Result<(), Error> {
+    let mut campaign = storage::campaign::get_campaign(env, campaign_id)?;
+    let mut reports = storage::report::get_campaign_reports(env, campaign_id);
+
+    if reports.status != ReportStatus::UnderReview {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    // Later reports are weighed against the threshold from scratch
+    reports.status = status;
+    reports.pending_weight = 0;
+    reports.pending_from = reports.reports.len();
+    reports.resolved_by = Some(moderator.clone());
+    reports.resolution = reason.clone();
+    storage::report::set_campaign_reports(env, campaign_id, &reports);
+    storage::report::dequeue_report_review(env, campaign_id);
+
+    match status {
+        ReportStatus::Upheld => record_challenge_upheld(env, &campaign.creator)?,
+        _ => {
+            campaign.withdrawals_paused = false;
+            storage::campaign::set_campaign(env, campaign_id, &campaign);
+        }
+    }
+
+    events::report::reports_resolved(env, campaign_id, moderator, status, reason);
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use crate::events;
+use crate::methods::{
+    math::{decrement, increment},
+    moderator::require_moderator,
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{
+        campaign::{Campaign, CampaignStatus},
+        reputation::Reputation,
+    },
+};
+use soroban_sdk::{Address, Env, String};
+
+/// Every creator starts from the middle of the 0-1000 scale
+const BASE_SCORE: i64 = 500;
+const MAX_SCORE: i64 = 1000;
+const ON_TIME_MILESTONE_POINTS: i64 = 20;
+const LATE_MILESTONE_POINTS: i64 = 5;
+const REJECTED_MILESTONE_PENALTY: i64 = 30;
+const CANCELLED_CAMPAIGN_PENALTY: i64 = 100;
+const UPHELD_CHALLENGE_PENALTY: i64 = 200;
+const FRAUD_FINDING_PENALTY: i64 = 500;
+const FRAUD_FLAG_PENALTY: i64 = 150;
+
+const MIN_RATING: u32 = 1;
+const MAX_RATING: u32 = 5;
+
+/// Rate a finished campaign from 1 to 5 (Contributor only)
+///
+/// Rating the same campaign again replaces the donor's previous rating.
+pub fn rate_campaign(
+    env: &Env,
+    donor: Address,
+    campaign_id: String,
+    rating: u32,
+) -> Result<(), Error> {
+    donor.require_auth();
+
+    if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+        return Err(Error::InvalidRating);
+    }
+
+    let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    if !is_finished(&campaign) {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    if !storage::contribution::has_contribution(env, &campaign_id, &donor) {
+        return Err(Error::ContributionNotFound);
+    }
+
+    let previous = storage::reputation::get_rating(env, &campaign_id, &donor);
+    let mut reputation = storage::reputation::get_reputation(env, &campaign.creator);
+    let ratings_total = match previous {
+        Some(previous) => reputation
+            .ratings_total
+            .checked_sub(previous)
+            .ok_or(Error::MathUnderflow)?,
+        None => {
+            reputation.ratings_count = increment(reputation.ratings_count)?;
+            reputation.ratings_total
+        }
+    };
+    reputation.ratings_total = ratings_total
+        .checked_add(rating)
+        .ok_or(Error::MathOverflow)?;
+
+    storage::reputation::set_rating(env, &campaign_id, &donor, rating);
+    storage::reputation::set_reputation(env, &campaign.creator, &reputation);
+
+    events::reputation::campaign_rated(env, &campaign_id, &donor, rating);
+
+    Ok(())
+}
+
+/// Uphold a challenge raised against a campaign's creator (Moderator only)
+pub fn uphold_challenge(
+    env: &Env,
+    moderator: Address,
+    campaign_id: String,
+    reason: String,
+) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+
+    let campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    record_challenge_upheld(env, &campaign.creator)?;
+
+    events::reputation::challenge_upheld(env, &campaign_id, &campaign.creator, reason);
+
+    Ok(())
+}
+
+/// Get the raw reputation counters of a creator
+pub fn get_reputation(env: &Env, creator: &Address) -> Reputation {
+    storage::reputation::get_reputation(env, creator)
+}
+
+/// Deterministic reputation score of a creator, from 0 to 1000
+///
+/// Starts at 500, adds 20 per on-time and 5 per late milestone, subtracts
+/// 30 per rejected milestone, 100 per cancelled campaign, 150 per fraud flag,
+/// 200 per upheld challenge and 500 per fraud finding, then shifts by up to
+/// ±100 depending on the average donor rating (3 stars is neutral). The
+/// result is clamped to the 0-1000 range.
+pub fn reputation(env: &Env, creator: &Address) -> u32 {
+    let counters = storage::reputation::get_reputation(env, creator);
+
+    let mut score = BASE_SCORE
+        + i64::from(counters.milestones_on_time) * ON_TIME_MILESTONE_POINTS
+        + i64::from(counters.milestones_late) * LATE_MILESTONE_POINTS
+        - i64::from(counters.milestones_rejected) * REJECTED_MILESTONE_PENALTY
+        - i64::from(counters.campaigns_cancelled) * CANCELLED_CAMPAIGN_PENALTY
+        - i64::from(counters.challenges_upheld) * UPHELD_CHALLENGE_PENALTY
+        - i64::from(counters.fraud_findings) * FRAUD_FINDING_PENALTY
+        - i64::from(counters.fraud_flags) * FRAUD_FLAG_PENALTY;
+
+    if counters.ratings_count > 0 {
+        // Average rating in hundredths (100-500), centred on 3 stars
+        let average = i64::from(counters.ratings_total) * 100 / i64::from(counters.ratings_count);
+        score += (average - 300) / 2;
+    }
+
+    score.clamp(0, MAX_SCORE) as u32
+}
+
+/// Record a validated milestone as on time or late for the creator
+pub(crate) fn record_milestone_completed(
+    env: &Env,
+    creator: &Address,
+    on_time: bool,
+) -> Result<(), Error> {
+    let mut reputation = storage::reputation::get_reputation(env, creator);
+    if on_time {
+        reputation.milestones_on_time = increment(reputation.milestones_on_time)?;
+    } else {
+        reputation.milestones_late = increment(reputation.milestones_late)?;
+    }
+    storage::reputation::set_reputation(env, creator, &reputation);
+
+    Ok(())
+}
+
+/// Replace the on-time or late credit of a rolled back milestone with a rejection
+pub(crate) fn record_milestone_rolled_back(
+    env: &Env,
+    creator: &Address,
+    on_time: bool,
+) -> Result<(), Error> {
+    let mut reputation = storage::reputation::get_reputation(env, creator);
+    if on_time {
+        reputation.milestones_on_time = decrement(reputation.milestones_on_time)?;
+    } else {
+        reputation.milestones_late = decrement(reputation.milestones_late)?;
+    }
+    reputation.milestones_rejected = increment(reputation.milestones_rejected)?;
+    storage::reputation::set_reputation(env, creator, &reputation);
+
+    Ok(())
+}
+
+/// Record a milestone proof rejected by the admin for the creator
+pub(crate) fn record_milestone_rejected(env: &Env, creator: &Address) -> Result<(), Error> {
+    let mut reputation = storage::reputation::get_reputation(env, creator);
+    reputation.milestones_rejected = increment(reputation.milestones_rejected)?;
+    storage::reputation::set_reputation(env, creator, &reputation);
+
+    Ok(())
+}
+
+/// Record a cancelled campaign for the creator
+pub(crate) fn record_campaign_cancelled(env: &Env, creator: &Address) -> Result<(), Error> {
+    let mut reputation = storage::reputation::get_reputation(env, creator);
+    reputation.campaigns_cancelled = increment(reputation.campaigns_cancelled)?;
+    storage::reputation::set_reputation(env, creator, &reputation);
+
+    Ok(())
+}
+
+/// Record an upheld challenge against the creator
+pub(crate) fn record_challenge_upheld(env: &Env, creator: &Address) -> Result<(), Error> {
+    let mut reputation = storage::reputation::get_reputation(env, creator);
+    reputation.challenges_upheld = increment(reputation.challenges_upheld)?;
+    storage::reputation::set_reputation(env, creator, &reputation);
+
+    Ok(())
+}
+
+/// Record a fraud finding against the creator
+pub(crate) fn record_fraud_finding(env: &Env, creator: &Address) -> Result<(), Error> {
+    let mut reputation = storage::reputation::get_reputation(env, creator);
+    reputation.fraud_findings = increment(reputation.fraud_findings)?;
+    storage::reputation::set_reputation(env, creator, &reputation);
+
+    Ok(())
+}
+
+/// Record a proof revoked after release as a fraud flag against the creator
+pub(crate) fn record_fraud_flag(env: &Env, creator: &Address) -> Result<(), Error> {
+    let mut reputation = storage::reputation::get_reputation(env, creator);
+    reputation.fraud_flags = increment(reputation.fraud_flags)?;
+    storage::reputation::set_reputation(env, creator, &reputation);
+
+    Ok(())
+}
+
+/// A campaign is finished once cancelled or once every milestone is validated
+fn is_finished(campaign: &Campaign) -> bool {
+    campaign.status == CampaignStatus::Cancelled
+        || (campaign.milestones_count > 0
+            && campaign.current_milestone == campaign.milestones_count)
+}
@@ -0,0 +1,138 @@
+use crate::events;
+use crate::methods::moderator::require_moderator;
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{campaign::CampaignStatus, review::*},
+};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Approve a pending campaign so it can accept donations (Moderator only)
+pub fn approve_campaign(env: &Env, moderator: Address, campaign_id: String) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+
+    review_campaign(
+        env,
+        &moderator,
+        &campaign_id,
+        ReviewAction::Approved,
+        String::from_str(env, ""),
+        CampaignStatus::Approved,
+    )
+}
+
+/// Permanently reject a campaign with a reason (Moderator only)
+pub fn reject_campaign(
+    env: &Env,
+    moderator: Address,
+    campaign_id: String,
+    reason: String,
+) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+
+    review_campaign(
+        env,
+        &moderator,
+        &campaign_id,
+        ReviewAction::Rejected,
+        reason,
+        CampaignStatus::Rejected,
+    )
+}
+
+/// Send a campaign back to its creator for changes (Moderator only)
+pub fn request_campaign_changes(
+    env: &Env,
+    moderator: Address,
+    campaign_id: String,
+    reason: String,
+) -> Result<(), Error> {
+    require_moderator(env, &moderator)?;
+
+    review_campaign(
+        env,
+        &moderator,
+        &campaign_id,
+        ReviewAction::ChangesRequested,
+        reason,
+        CampaignStatus::ChangesRequested,
+    )
+}
+
+/// Revise a campaign after a change request and queue it for review again (Creator only)
+pub fn resubmit_campaign(
+    env: &Env,
+    campaign_id: String,
+    title: String,
+    description: String,
+) -> Result<(), Error> {
+    let mut campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+    campaign.creator.require_auth();
+
+    if campaign.status != CampaignStatus::ChangesRequested {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    campaign.title = title;
+    campaign.description = description;
+    storage::campaign::set_campaign(env, &campaign_id, &campaign);
+
+    review_campaign(
+        env,
+        &campaign.creator,
+        &campaign_id,
+        ReviewAction::Resubmitted,
+        String::from_str(env, ""),
+        CampaignStatus::PendingReview,
+    )
+}
+
+/// Get the full review history of a campaign
+pub fn get_campaign_reviews(env: &Env, campaign_id: &String) -> Result<Vec<ReviewEntry>, Error> {
+    if !storage::campaign::has_campaign(env, campaign_id) {
+        return Err(Error::CampaignNotFound);
+    }
+
+    Ok(storage::review::get_reviews(env, campaign_id))
+}
+
+fn review_campaign(
+    env: &Env,
+    reviewer: &Address,
+    campaign_id: &String,
+    action: ReviewAction,
+    reason: String,
+    status: CampaignStatus,
+) -> Result<(), Error> {
+    let mut campaign = storage::campaign::get_campaign(env, campaign_id)?;
+
+    // Only campaigns still under review can change status here
+    let allowed = match action {
+        ReviewAction::Resubmitted => campaign.status == CampaignStatus::ChangesRequested,
+        ReviewAction::Rejected => matches!(
+            campaign.status,
+            CampaignStatus::PendingReview | CampaignStatus::ChangesRequested
+        ),
+        ReviewAction::Approved | ReviewAction::ChangesRequested => {
+            campaign.status == CampaignStatus::PendingReview
+        }
+    };
+    if !allowed {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    campaign.status = status;
+    storage::campaign::set_campaign(env, campaign_id, &campaign);
+
+    let review = ReviewEntry {
+        reviewer: reviewer.clone(),
+        action,
+        reason,
+        timestamp: env.ledger().timestamp(),
+    };
+    storage::review::add_review(env, campaign_id, &review);
+
+    events::review::campaign_reviewed(env, campaign_id, reviewer, action);
+
+    Ok(())
+}
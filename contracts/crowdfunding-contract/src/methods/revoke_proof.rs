@@ -0,0 +1,104 @@
+use crate::events;
+use crate::methods::{proof_milestone::roll_back_milestone, reputation::record_fraud_flag};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::campaign::{Campaign, CampaignStatus},
+};
+use soroban_sdk::{Address, Env, String};
+
+/// Revoke a proof found to be false (Admin only)
+///
+/// The proof stays on record, marked as revoked. If nothing has been paid
+/// out for the milestone it validated, that milestone and any validated after
+/// it roll back to incomplete. Once funds were released the revocation is
+/// recorded as a fraud flag against the creator instead.
+pub fn revoke_proof(
+    env: &Env,
+    campaign_id: String,
+    proof_id: String,
+    reason: String,
+) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let mut proof = storage::proof::get_proof(env, &campaign_id, &proof_id)?;
+    if proof.revoked_at.is_some() {
+        return Err(Error::ProofRevoked);
+    }
+
+    proof.revoked_at = Some(env.ledger().timestamp());
+    proof.revocation_reason = Some(reason.clone());
+    storage::proof::set_proof(env, &campaign_id, &proof_id, &proof);
+
+    if storage::campaign::has_campaign(env, &campaign_id) {
+        let mut campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+        revoke_validation(env, &mut campaign, &proof_id)?;
+    } else {
+        // Archived campaigns have paid out everything they raised
+        let settlement = storage::settlement::get_settlement(env, &campaign_id)?;
+        flag_fraud(env, &campaign_id, &settlement.creator, &proof_id)?;
+    }
+
+    events::proof::proof_revoked(env, &campaign_id, &proof_id, reason);
+
+    Ok(())
+}
+
+/// Undo the milestone validation that relied on a revoked proof
+fn revoke_validation(env: &Env, campaign: &mut Campaign, proof_id: &String) -> Result<(), Error> {
+    let mut validated = None;
+    let mut released = false;
+    for sequence in 1..=campaign.current_milestone {
+        let milestone = storage::milestone::get_milestone(env, &campaign.id, sequence)?;
+        // A proof reused across milestones rolls back from the first it validated
+        if validated.is_none() && milestone.proof_id.as_ref() == Some(proof_id) {
+            validated = Some(sequence);
+        }
+        if validated.is_some() && milestone.claimed_amount > 0 {
+            released = true;
+        }
+    }
+
+    // A proof that never validated a milestone unlocked nothing
+    let Some(sequence) = validated else {
+        return Ok(());
+    };
+
+    if released {
+        flag_fraud(env, &campaign.id, &campaign.creator, proof_id)?;
+        return Ok(());
+    }
+
+    // The escrow of a frozen campaign is already being clawed back
+    if campaign.status == CampaignStatus::Frozen {
+        return Ok(());
+    }
+
+    // Later validations build on this one, so they roll back as well
+    while campaign.current_milestone >= sequence {
+        let milestone =
+            storage::milestone::get_milestone(env, &campaign.id, campaign.current_milestone)?;
+        roll_back_milestone(env, campaign, milestone)?;
+        events::milestone::milestone_rolled_back(
+            env,
+            campaign.id.clone(),
+            campaign.current_milestone + 1,
+        );
+    }
+    storage::campaign::set_campaign(env, &campaign.id, campaign);
+
+    Ok(())
+}
+
+fn flag_fraud(
+    env: &Env,
+    campaign_id: &String,
+    creator: &Address,
+    proof_id: &String,
+) -> Result<(), Error> {
+    record_fraud_flag(env, creator)?;
+    events::fraud::fraud_flagged(env, campaign_id.clone(), creator, proof_id.clone());
+
+    Ok(())
+}
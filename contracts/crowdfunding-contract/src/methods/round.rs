@@ -0,0 +1,311 @@
+use crate::events;
+use crate::methods::{
+    escrow::{adjust_liabilities, pay_out, receive},
+    math::{add, mul, mul_div, sub},
+    organization::record_raised,
+    stats::{record_match, record_match_return},
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{
+        campaign::{Campaign, CampaignStatus},
+        round::*,
+    },
+};
+use soroban_sdk::{Address, Env, Map, String, Vec};
+
+/// Contributions are scaled by this factor before taking the square root,
+/// so every root keeps three decimal digits of precision.
+const SQRT_PRECISION: i128 = 1_000_000;
+
+/// Fixed-point precision used when converting a campaign's weight into a
+/// share of the matching pool.
+const SHARE_PRECISION: i128 = 1_000_000_000;
+
+/// Create a new matching round (Admin only)
+pub fn create_round(env: &Env, name: String) -> Result<u32, Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let round_id = storage::round::next_round_id(env);
+    let round = Round {
+        id: round_id,
+        name: name.clone(),
+        status: RoundStatus::Open,
+        campaigns: Vec::new(env),
+        matching_pool: 0,
+        funders: Map::new(env),
+        distributed: 0,
+        matches: Map::new(env),
+        returned: 0,
+        claimed: Map::new(env),
+        created_at: env.ledger().timestamp(),
+        finalized_at: None,
+    };
+
+    storage::round::set_round(env, round_id, &round);
+    events::round::round_created(env, round_id, name);
+
+    Ok(round_id)
+}
+
+/// Enrol a campaign in an open matching round (Admin only)
+pub fn enroll_campaign(env: &Env, round_id: u32, campaign_id: String) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let mut round = storage::round::get_round(env, round_id)?;
+    if round.status != RoundStatus::Open {
+        return Err(Error::InvalidRoundState);
+    }
+
+    // Only live campaigns can put matched funds to use
+    if storage::campaign::get_campaign(env, &campaign_id)?.status != CampaignStatus::Approved {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    if round.campaigns.contains(&campaign_id) {
+        return Ok(());
+    }
+
+    round.campaigns.push_back(campaign_id.clone());
+    storage::round::set_round(env, round_id, &round);
+
+    events::round::campaign_enrolled(env, round_id, campaign_id);
+
+    Ok(())
+}
+
+/// Deposit funds into the matching pool of an open round
+pub fn fund_round(env: &Env, funder: Address, round_id: u32, amount: i128) -> Result<(), Error> {
+    funder.require_auth();
+
+    if amount <= 0 {
+        return Err(Error::AmountMustBePositive);
+    }
+
+    let mut round = storage::round::get_round(env, round_id)?;
+    if round.status != RoundStatus::Open {
+        return Err(Error::InvalidRoundState);
+    }
+
+    let received = receive(env, &funder, amount)?;
+    adjust_liabilities(env, received)?;
+
+    round.matching_pool = add(round.matching_pool, received)?;
+    let deposited = round.funders.get(funder.clone()).unwrap_or(0);
+    round.funders.set(funder.clone(), add(deposited, received)?);
+    storage::round::set_round(env, round_id, &round);
+
+    events::round::round_funded(env, round_id, &funder, received);
+
+    Ok(())
+}
+
+/// Distribute the matching pool with the quadratic funding formula (Admin only)
+///
+/// Each campaign's weight is `(Σ √c)² - Σ c` over its per-donor contribution
+/// records, and the pool is split in proportion to those weights. The matched
+/// amount is credited to the campaign escrow, so it is only released through
+/// the regular milestone flow. Campaigns archived or no longer approved since
+/// they enrolled are left out of the split. When no campaign has any weight
+/// the pool goes back to its funders. Matched funds a campaign could not
+/// spend before it closed are handed back and claimed with
+/// `claim_round_return`.
+pub fn finalize_round(env: &Env, round_id: u32) -> Result<(), Error> {
+    let admin = storage::admin::get_admin(env);
+    admin.require_auth();
+
+    let mut round = storage::round::get_round(env, round_id)?;
+    if round.status != RoundStatus::Open {
+        return Err(Error::InvalidRoundState);
+    }
+
+    let mut weights: Vec<i128> = Vec::new(env);
+    let mut total_weight: i128 = 0;
+    for campaign_id in round.campaigns.iter() {
+        let weight = if is_matchable(env, &campaign_id)? {
+            campaign_weight(env, &campaign_id)?
+        } else {
+            0
+        };
+        total_weight = add(total_weight, weight)?;
+        weights.push_back(weight);
+    }
+
+    // The pool stops being owed to the round; matched funds are owed again
+    // through each campaign's escrow
+    adjust_liabilities(env, -round.matching_pool)?;
+
+    if total_weight > 0 {
+        for (index, campaign_id) in round.campaigns.iter().enumerate() {
+            let weight = weights.get_unchecked(index as u32);
+            let share = mul_div(weight, SHARE_PRECISION, total_weight)?;
+            let matched = mul_div(round.matching_pool, share, SHARE_PRECISION)?;
+
+            if matched == 0 {
+                continue;
+            }
+
+            let mut campaign = storage::campaign::get_campaign(env, &campaign_id)?;
+            campaign.total_raised = add(campaign.total_raised, matched)?;
+            campaign.matched_amount = add(campaign.matched_amount, matched)?;
+            storage::campaign::set_campaign(env, &campaign_id, &campaign);
+            record_raised(env, &campaign, matched)?;
+            record_match(env, &campaign_id, matched)?;
+
+            round.distributed = add(round.distributed, matched)?;
+            round.matches.set(campaign_id.clone(), matched);
+            storage::round::add_campaign_round(env, &campaign_id, round_id);
+
+            events::round::campaign_matched(env, round_id, campaign_id, matched);
+        }
+    }
+
+    // Nothing could be matched, so every funder gets their deposit back.
+    // Otherwise rounding dust left in the pool is owed to no one.
+    if round.distributed == 0 {
+        for (funder, deposited) in round.funders.iter() {
+            pay_out(env, &funder, deposited)?;
+            events::round::deposit_returned(env, round_id, &funder, deposited);
+        }
+    }
+
+    round.status = RoundStatus::Finalized;
+    round.finalized_at = Some(env.ledger().timestamp());
+    storage::round::set_round(env, round_id, &round);
+
+    events::round::round_finalized(env, round_id, round.distributed);
+
+    Ok(())
+}
+
+/// Claim a funder's share of the matched funds handed back to a round
+///
+/// Funders share what closed campaigns returned in proportion to their
+/// deposits, and can claim again as more campaigns return funds.
+pub fn claim_round_return(env: &Env, funder: Address, round_id: u32) -> Result<i128, Error> {
+    funder.require_auth();
+
+    let mut round = storage::round::get_round(env, round_id)?;
+    let deposited = round.funders.get(funder.clone()).unwrap_or(0);
+    if deposited <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    let claimed = round.claimed.get(funder.clone()).unwrap_or(0);
+    let owed = sub(
+        mul_div(deposited, round.returned, round.matching_pool)?,
+        claimed,
+    )?;
+    if owed <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
+
+    round.claimed.set(funder.clone(), add(claimed, owed)?);
+    storage::round::set_round(env, round_id, &round);
+    adjust_liabilities(env, -owed)?;
+
+    pay_out(env, &funder, owed)?;
+    events::round::return_claimed(env, round_id, &funder, owed);
+
+    Ok(owed)
+}
+
+/// Hand the unspent part of a campaign's matched funds back to its rounds
+///
+/// `available` is the escrow the creator can no longer be paid. Matched funds
+/// own the same fraction of it as of the total raised, and that part is split
+/// across the rounds in proportion to what each of them matched. The campaign
+/// holds no matched funds afterwards; the caller stores it.
+pub(crate) fn return_match(
+    env: &Env,
+    campaign: &mut Campaign,
+    available: i128,
+) -> Result<i128, Error> {
+    let matched = campaign.matched_amount;
+    if matched <= 0 {
+        return Ok(0);
+    }
+    let amount = mul_div(matched, available.max(0), campaign.total_raised)?.min(matched);
+
+    // The last round takes the rounding remainder
+    let round_ids = storage::round::get_campaign_rounds(env, &campaign.id);
+    let mut remaining = amount;
+    for (index, round_id) in round_ids.iter().enumerate() {
+        let mut round = storage::round::get_round(env, round_id)?;
+        let part = if index as u32 + 1 == round_ids.len() {
+            remaining
+        } else {
+            let round_match = round.matches.get(campaign.id.clone()).unwrap_or(0);
+            mul_div(amount, round_match, matched)?
+        };
+        remaining = sub(remaining, part)?;
+
+        round.returned = add(round.returned, part)?;
+        storage::round::set_round(env, round_id, &round);
+        events::round::match_returned(env, round_id, campaign.id.clone(), part);
+    }
+
+    campaign.total_raised = sub(campaign.total_raised, matched)?;
+    campaign.matched_amount = 0;
+    record_raised(env, campaign, -amount)?;
+    record_match_return(env, &campaign.id, amount)?;
+    adjust_liabilities(env, amount)?;
+
+    Ok(amount)
+}
+
+/// Get matching round details
+pub fn get_round(env: &Env, round_id: u32) -> Result<Round, Error> {
+    storage::round::get_round(env, round_id)
+}
+
+/// Whether a campaign can still be credited matched funds
+fn is_matchable(env: &Env, campaign_id: &String) -> Result<bool, Error> {
+    if !storage::campaign::has_campaign(env, campaign_id) {
+        return Ok(false);
+    }
+
+    Ok(storage::campaign::get_campaign(env, campaign_id)?.status == CampaignStatus::Approved)
+}
+
+/// Quadratic funding weight of a campaign, scaled by `SQRT_PRECISION`
+fn campaign_weight(env: &Env, campaign_id: &String) -> Result<i128, Error> {
+    let mut sum_of_roots: i128 = 0;
+    let mut sum_of_amounts: i128 = 0;
+
+    for index in 0..storage::contribution::get_contributor_count(env, campaign_id) {
+        let contributor = storage::contribution::get_contributor(env, campaign_id, index);
+        let amount = storage::contribution::get_contribution(env, campaign_id, &contributor);
+        let scaled = mul(amount, SQRT_PRECISION)?;
+
+        sum_of_roots = add(sum_of_roots, isqrt(scaled))?;
+        sum_of_amounts = add(sum_of_amounts, scaled)?;
+    }
+
+    let squared = mul(sum_of_roots, sum_of_roots)?;
+
+    // Flooring each root can leave the square marginally below the plain sum
+    Ok(if squared > sum_of_amounts {
+        sub(squared, sum_of_amounts)?
+    } else {
+        0
+    })
+}
+
+/// Integer square root (floor) using Newton's method
+fn isqrt(value: i128) -> i128 {
+    if value < 2 {
+        return value.max(0);
+    }
+
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
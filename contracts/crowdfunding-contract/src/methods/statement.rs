@@ -0,0 +1,140 @@
+use crate::methods::math::{add, sub};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::{
+        campaign::Campaign,
+        statement::{GivingLine, GivingStatement, OrganizationTotal},
+    },
+};
+use soroban_sdk::{Address, Env, Vec};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Get a donor's giving statement for a UTC calendar year
+///
+/// Gifts count in the year they were made and refunds in the year they were
+/// paid, both taken from ledger timestamps.
+pub fn get_giving_statement(
+    env: &Env,
+    donor: &Address,
+    year: u32,
+) -> Result<GivingStatement, Error> {
+    let lines = storage::statement::get_giving_lines(env, donor, year);
+
+    let mut organizations: Vec<OrganizationTotal> = Vec::new(env);
+    let mut total_contributed: i128 = 0;
+    let mut total_refunded: i128 = 0;
+    for line in lines.iter() {
+        total_contributed = add(total_contributed, line.contributed)?;
+        total_refunded = add(total_refunded, line.refunded)?;
+
+        let Some(organization_id) = line.organization_id else {
+            continue;
+        };
+        match organizations
+            .iter()
+            .position(|total| total.organization_id == organization_id)
+        {
+            Some(index) => {
+                let mut total = organizations.get_unchecked(index as u32);
+                total.net = add(total.net, line.net)?;
+                organizations.set(index as u32, total);
+            }
+            None => organizations.push_back(OrganizationTotal {
+                organization_id,
+                net: line.net,
+            }),
+        }
+    }
+
+    Ok(GivingStatement {
+        donor: donor.clone(),
+        year,
+        lines,
+        organizations,
+        total_contributed,
+        total_refunded,
+        total_net: sub(total_contributed, total_refunded)?,
+    })
+}
+
+/// Record a gift in the donor's statement for the current year
+pub(crate) fn record_giving(
+    env: &Env,
+    campaign: &Campaign,
+    donor: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    update_line(env, campaign, donor, |line| {
+        line.contributed = add(line.contributed, amount)?;
+        Ok(())
+    })
+}
+
+/// Record funds returned to the donor in their statement for the current year
+pub(crate) fn record_giving_refund(
+    env: &Env,
+    campaign: &Campaign,
+    donor: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    update_line(env, campaign, donor, |line| {
+        line.refunded = add(line.refunded, amount)?;
+        Ok(())
+    })
+}
+
+fn update_line<F>(env: &Env, campaign: &Campaign, donor: &Address, update: F) -> Result<(), Error>
+where
+    F: FnOnce(&mut GivingLine) -> Result<(), Error>,
+{
+    let year = year_of(env.ledger().timestamp());
+    let mut lines = storage::statement::get_giving_lines(env, donor, year);
+
+    let index = match lines
+        .iter()
+        .position(|line| line.campaign_id == campaign.id)
+    {
+        Some(index) => index as u32,
+        None => {
+            lines.push_back(GivingLine {
+                campaign_id: campaign.id.clone(),
+                organization_id: campaign.organization_id,
+                contributed: 0,
+                refunded: 0,
+                net: 0,
+            });
+            lines.len() - 1
+        }
+    };
+
+    let mut line = lines.get_unchecked(index);
+    update(&mut line)?;
+    line.net = sub(line.contributed, line.refunded)?;
+    lines.set(index, line);
+
+    storage::statement::set_giving_lines(env, donor, year, &lines);
+    Ok(())
+}
+
+/// UTC calendar year of a unix timestamp
+///
+/// Converts days since 1970-01-01 to a proleptic Gregorian date using
+/// 400-year eras that start on March 1st.
+pub(crate) fn year_of(timestamp: u64) -> u32 {
+    let days = timestamp / SECONDS_PER_DAY + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153; // 0 = March
+
+    let year = year_of_era + era * 400;
+    if month_index >= 10 {
+        (year + 1) as u32
+    } else {
+        year as u32
+    }
+}
@@ -0,0 +1,147 @@
+use crate::methods::{
+    escrow::adjust_liabilities,
+    math::{add, increment, mul_div, sub, BPS_DENOMINATOR},
+};
+use crate::storage::types::error::Error;
+use crate::storage::{
+    self,
+    structs::stats::{CampaignStats, CampaignTally},
+};
+use soroban_sdk::{Env, String};
+
+/// Get the dashboard figures of a campaign
+///
+/// Every figure comes from totals kept up to date as the campaign receives
+/// and pays out funds, so reading them costs the same for any campaign size.
+pub fn get_campaign_stats(env: &Env, campaign_id: &String) -> Result<CampaignStats, Error> {
+    let campaign = storage::campaign::get_campaign(env, campaign_id)?;
+    let tally = storage::stats::get_campaign_tally(env, campaign_id);
+
+    let average_gift = match tally.contributions {
+        0 => 0,
+        contributions => tally.gifts_amount / i128::from(contributions),
+    };
+    let progress_bps =
+        mul_div(campaign.total_raised, BPS_DENOMINATOR, campaign.goal)?.min(BPS_DENOMINATOR) as u32;
+
+    // Seconds until the due date of the first milestone not validated yet
+    let now = env.ledger().timestamp();
+    let time_left = if campaign.current_milestone < campaign.milestones_count {
+        storage::milestone::get_milestone(env, campaign_id, campaign.current_milestone + 1)?
+            .due_date
+            .map(|due_date| due_date.saturating_sub(now))
+    } else {
+        None
+    };
+
+    Ok(CampaignStats {
+        unique_donors: campaign.supporters,
+        contributions: tally.contributions,
+        average_gift,
+        median_gift_bucket: median_gift_bucket(&tally),
+        gift_buckets: tally.gift_buckets,
+        progress_bps,
+        escrowed_amount: tally.escrowed_amount,
+        released_amount: tally.released_amount,
+        refunded_amount: tally.refunded_amount,
+        withdrawable_amount: campaign.withdrawable_amount,
+        time_left,
+    })
+}
+
+/// Record a gift received by a campaign
+pub(crate) fn record_gift(env: &Env, campaign_id: &String, amount: i128) -> Result<(), Error> {
+    update_tally(env, campaign_id, |tally| {
+        let bucket = gift_bucket(amount);
+        while tally.gift_buckets.len() <= bucket {
+            tally.gift_buckets.push_back(0);
+        }
+        tally
+            .gift_buckets
+            .set(bucket, increment(tally.gift_buckets.get_unchecked(bucket))?);
+
+        tally.contributions = increment(tally.contributions)?;
+        tally.gifts_amount = add(tally.gifts_amount, amount)?;
+        tally.escrowed_amount = add(tally.escrowed_amount, amount)?;
+        Ok(())
+    })
+}
+
+/// Record matching funds credited to a campaign
+pub(crate) fn record_match(env: &Env, campaign_id: &String, amount: i128) -> Result<(), Error> {
+    update_tally(env, campaign_id, |tally| {
+        tally.escrowed_amount = add(tally.escrowed_amount, amount)?;
+        Ok(())
+    })
+}
+
+/// Record escrow paid to the creator or the fallback beneficiary
+pub(crate) fn record_release(env: &Env, campaign_id: &String, amount: i128) -> Result<(), Error> {
+    update_tally(env, campaign_id, |tally| {
+        tally.escrowed_amount = sub(tally.escrowed_amount, amount)?;
+        tally.released_amount = add(tally.released_amount, amount)?;
+        Ok(())
+    })
+}
+
+/// Record escrow returned to contributors
+pub(crate) fn record_refund(env: &Env, campaign_id: &String, amount: i128) -> Result<(), Error> {
+    update_tally(env, campaign_id, |tally| {
+        tally.escrowed_amount = sub(tally.escrowed_amount, amount)?;
+        tally.refunded_amount = add(tally.refunded_amount, amount)?;
+        Ok(())
+    })
+}
+
+/// Record matching funds handed back to their rounds
+pub(crate) fn record_match_return(
+    env: &Env,
+    campaign_id: &String,
+    amount: i128,
+) -> Result<(), Error> {
+    update_tally(env, campaign_id, |tally| {
+        tally.escrowed_amount = sub(tally.escrowed_amount, amount)?;
+        Ok(())
+    })
+}
+
+/// Record escrow taken back by the token issuer
+pub(crate) fn record_clawback(env: &Env, campaign_id: &String, amount: i128) -> Result<(), Error> {
+    update_tally(env, campaign_id, |tally| {
+        tally.escrowed_amount = sub(tally.escrowed_amount, amount)?;
+        tally.clawed_back_amount = add(tally.clawed_back_amount, amount)?;
+        Ok(())
+    })
+}
+
+fn update_tally<F>(env: &Env, campaign_id: &String, update: F) -> Result<(), Error>
+where
+    F: FnOnce(&mut CampaignTally) -> Result<(), Error>,
+{
+    let mut tally = storage::stats::get_campaign_tally(env, campaign_id);
+    let owed = tally.escrowed_amount.max(0);
+    update(&mut tally)?;
+    storage::stats::set_campaign_tally(env, campaign_id, &tally);
+
+    // A campaign that paid out more than it held owes nothing, so the excess
+    // shows as a shortfall instead of being absorbed by other campaigns
+    adjust_liabilities(env, sub(tally.escrowed_amount.max(0), owed)?)
+}
+
+/// Order of magnitude of a positive gift
+fn gift_bucket(amount: i128) -> u32 {
+    amount.max(1).ilog10()
+}
+
+/// Lower bound of the bucket holding the median gift, 0 without gifts
+fn median_gift_bucket(tally: &CampaignTally) -> i128 {
+    let middle = tally.contributions.div_ceil(2);
+    let mut seen = 0;
+    for (bucket, count) in tally.gift_buckets.iter().enumerate() {
+        seen += count;
+        if seen >= middle && count > 0 {
+            return 10_i128.pow(bucket as u32);
+        }
+    }
+    0
+}
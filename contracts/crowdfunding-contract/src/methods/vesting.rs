@@ -0,0 +1,51 @@
+use crate::methods::math::mul_div;
+use crate::storage::structs::milestone::{Milestone, Vesting};
+use crate::storage::types::error::Error;
+
+/// Check that a vesting schedule has a positive duration and a cliff inside it
+pub(crate) fn validate_vesting(vesting: &Vesting) -> Result<(), Error> {
+    if let Vesting::Linear(schedule) = vesting {
+        if schedule.end <= schedule.start {
+            return Err(Error::InvalidVestingSchedule);
+        }
+        if let Some(cliff) = schedule.cliff {
+            if cliff < schedule.start || cliff > schedule.end {
+                return Err(Error::InvalidVestingSchedule);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Part of a milestone's tranche released to the creator at time `at`
+///
+/// Nothing is released before validation. Without a vesting schedule the
+/// whole tranche is released on validation; with one, it vests linearly
+/// between `start` and `end` and nothing is released before the cliff.
+pub(crate) fn released_amount(
+    milestone: &Milestone,
+    tranche: i128,
+    at: u64,
+) -> Result<i128, Error> {
+    if !milestone.completed {
+        return Ok(0);
+    }
+
+    let schedule = match &milestone.vesting {
+        Vesting::Linear(schedule) => schedule,
+        Vesting::Immediate => return Ok(tranche),
+    };
+
+    if at < schedule.cliff.unwrap_or(schedule.start) || at <= schedule.start {
+        return Ok(0);
+    }
+    if at >= schedule.end {
+        return Ok(tranche);
+    }
+
+    let elapsed = i128::from(at - schedule.start);
+    let duration = i128::from(schedule.end - schedule.start);
+
+    mul_div(tranche, elapsed, duration)
+}
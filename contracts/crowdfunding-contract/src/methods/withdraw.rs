@@ -1,34 +1,63 @@
 use crate::{
     events,
-    methods::token::token_transfer,
+    methods::{
+        escrow::pay_out, finalize_campaign::record_settlement, organization::record_released,
+        stats::record_release,
+    },
     storage::{
         campaign::{get_campaign, remove_campaign},
+        stats::get_campaign_tally,
+        structs::campaign::CampaignStatus,
         types::error::Error,
     },
 };
 use soroban_sdk::{Env, String};
 
+/// Pay the whole escrow of a campaign without milestones (Creator only)
+///
+/// The campaign must be approved and have reached its goal. It is archived as
+/// a settlement afterwards.
 pub fn withdraw(env: &Env, campaign_id: String) -> Result<(), Error> {
     let campaign = get_campaign(env, &campaign_id)?;
 
     // Authorize the campaign creator
     campaign.creator.require_auth();
 
+    // Frozen or cancelled escrow belongs to contributors, and a completed
+    // campaign has already been paid out
+    if campaign.status != CampaignStatus::Approved {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    // Milestone campaigns pay out through withdraw_milestone_funds
+    if campaign.milestones_count > 0 {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    if campaign.withdrawals_paused {
+        return Err(Error::WithdrawalsPaused);
+    }
+
     // This logic is for a non-milestone, all-or-nothing campaign.
     if campaign.total_raised < campaign.goal {
         return Err(Error::CampaignGoalNotReached);
     }
 
-    token_transfer(
-        env,
-        &env.current_contract_address(),
-        &campaign.creator,
-        &campaign.total_raised,
-    )?;
+    // Pay what the escrow holds, which is less than the total raised once
+    // refunds were short or the issuer clawed funds back
+    let escrowed = get_campaign_tally(env, &campaign_id).escrowed_amount;
+    if escrowed <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
+    pay_out(env, &campaign.creator, escrowed)?;
+
+    record_released(env, &campaign, escrowed)?;
+    record_release(env, &campaign_id, escrowed)?;
 
-    // The campaign is now complete and can be removed.
+    // Archive a compact settlement, then prune the bulky campaign record
+    record_settlement(env, &campaign, escrowed, 0, None);
     remove_campaign(env, &campaign_id);
-    events::campaign::withdraw(env, &campaign.creator, campaign.total_raised);
+    events::campaign::withdraw(env, &campaign.creator, escrowed);
 
     Ok(())
 }
@@ -1,10 +1,26 @@
 use crate::events;
-use crate::methods::token::token_transfer;
+use crate::methods::{
+    escrow::pay_out,
+    math::{add, sub},
+    organization::record_released,
+    stats::record_release,
+    vesting::released_amount,
+};
 use crate::storage;
-use crate::storage::types::error::Error;
-use soroban_sdk::{Env, String};
+use crate::storage::{
+    structs::{
+        campaign::{Campaign, CampaignStatus},
+        milestone::Milestone,
+    },
+    types::error::Error,
+};
+use soroban_sdk::{Env, String, Vec};
 
 /// Withdraw funds up to completed milestone (Creator only)
+///
+/// Pays what milestones `1..=milestone_sequence` have released and not been
+/// withdrawn yet. Vesting tranches only count their vested part. The campaign
+/// must be approved; cancelling pays out what has vested by then.
 pub fn withdraw_milestone_funds(
     env: &Env,
     campaign_id: String,
@@ -15,8 +31,62 @@ pub fn withdraw_milestone_funds(
     // Creator authorization
     campaign.creator.require_auth();
 
+    pay_withdrawal(env, &mut campaign, milestone_sequence)
+}
+
+/// Pay the creator what milestones `1..=milestone_sequence` allow right now
+pub(crate) fn pay_withdrawal(
+    env: &Env,
+    campaign: &mut Campaign,
+    milestone_sequence: u32,
+) -> Result<i128, Error> {
+    let campaign_id = campaign.id.clone();
+
+    // Record the newly claimed part of each tranche
+    let (withdrawable, claimed) = plan_withdrawal(env, campaign, milestone_sequence)?;
+    for milestone in claimed.iter() {
+        storage::milestone::set_milestone(env, &campaign_id, milestone.sequence, &milestone);
+    }
+
+    // Update campaign state
+    campaign.withdrawable_amount = sub(campaign.withdrawable_amount, withdrawable)?;
+    storage::campaign::set_campaign(env, &campaign_id, campaign);
+    record_released(env, campaign, withdrawable)?;
+    record_release(env, &campaign_id, withdrawable)?;
+
+    // Transfer funds to creator
+    pay_out(env, &campaign.creator, withdrawable)?;
+
+    // Emit event
+    events::milestone::milestone_withdrawal(env, campaign_id, milestone_sequence, withdrawable);
+
+    Ok(withdrawable)
+}
+
+/// Check a milestone withdrawal and work out what it would pay
+///
+/// Returns the amount and the milestones whose claimed part grows, without
+/// writing them.
+pub(crate) fn plan_withdrawal(
+    env: &Env,
+    campaign: &Campaign,
+    milestone_sequence: u32,
+) -> Result<(i128, Vec<Milestone>), Error> {
+    let campaign_id = &campaign.id;
+
+    // Frozen or cancelled escrow belongs to contributors, and nothing is
+    // released before approval
+    if campaign.status != CampaignStatus::Approved {
+        return Err(Error::InvalidCampaignStatus);
+    }
+
+    // Donor reports under review hold every payout
+    if campaign.withdrawals_paused {
+        return Err(Error::WithdrawalsPaused);
+    }
+
     // Validate milestone is completed
-    let milestone = storage::milestone::get_milestone(env, &campaign_id, milestone_sequence)?;
+    let milestone = storage::milestone::get_milestone(env, campaign_id, milestone_sequence)?;
     if !milestone.completed {
         return Err(Error::MilestoneNotCompleted);
     }
@@ -26,31 +96,38 @@ pub fn withdraw_milestone_funds(
         return Err(Error::CannotWithdrawFutureMilestone);
     }
 
-    // Calculate withdrawable amount
-    let withdrawable = milestone.target_amount;
-    if withdrawable <= 0 {
-        return Err(Error::NoFundsToWithdraw);
+    // Nothing is paid until the release delay after validation has passed
+    let now = env.ledger().timestamp();
+    if milestone
+        .unlocks_at
+        .is_some_and(|unlocks_at| now < unlocks_at)
+    {
+        return Err(Error::ReleaseLocked);
     }
 
-    // Check if there are actually funds available
-    if campaign.withdrawable_amount < withdrawable {
-        return Err(Error::NoFundsToWithdraw);
-    }
+    // Collect the released but unclaimed part of each tranche
+    let released_at = campaign
+        .cancelled_at
+        .map_or(now, |cancelled_at| cancelled_at.min(now));
+    let mut withdrawable: i128 = 0;
+    let mut claimed = Vec::new(env);
+    let mut previous_target = 0;
+    for sequence in 1..=milestone_sequence {
+        let mut milestone = storage::milestone::get_milestone(env, campaign_id, sequence)?;
+        let tranche = sub(milestone.target_amount, previous_target)?;
+        previous_target = milestone.target_amount;
 
-    // Update campaign state
-    campaign.withdrawable_amount = 0; // Reset after withdrawal
-    storage::campaign::set_campaign(env, &campaign_id, &campaign);
-
-    // Transfer funds to creator
-    token_transfer(
-        env,
-        &env.current_contract_address(),
-        &campaign.creator,
-        &withdrawable,
-    )?;
+        let released = released_amount(&milestone, tranche, released_at)?;
+        if released > milestone.claimed_amount {
+            withdrawable = add(withdrawable, sub(released, milestone.claimed_amount)?)?;
+            milestone.claimed_amount = released;
+            claimed.push_back(milestone);
+        }
+    }
 
-    // Emit event
-    events::milestone::milestone_withdrawal(env, campaign_id, milestone_sequence, withdrawable);
+    if withdrawable <= 0 {
+        return Err(Error::NoFundsToWithdraw);
+    }
 
-    Ok(withdrawable)
+    Ok((withdrawable, claimed))
 }
@@ -0,0 +1,27 @@
+use crate::storage::{
+    structs::amendment::MilestoneAmendment,
+    types::{error::Error, storage::DataKey},
+};
+use soroban_sdk::{Env, String};
+
+pub(crate) fn amendment_key(campaign_id: &String) -> DataKey {
+    DataKey::MilestoneAmendment(campaign_id.clone())
+}
+
+pub(crate) fn set_amendment(env: &Env, campaign_id: &String, amendment: &MilestoneAmendment) {
+    let key = amendment_key(campaign_id);
+    env.storage().persistent().set(&key, amendment);
+}
+
+pub(crate) fn get_amendment(env: &Env, campaign_id: &String) -> Result<MilestoneAmendment, Error> {
+    let key = amendment_key(campaign_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::AmendmentNotFound)
+}
+
+pub(crate) fn remove_amendment(env: &Env, campaign_id: &String) {
+    let key = amendment_key(campaign_id);
+    env.storage().persistent().remove(&key);
+}
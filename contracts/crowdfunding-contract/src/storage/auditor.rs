@@ -0,0 +1,59 @@
+use soroban_sdk::{Address, Env, String, Vec};
+
+use super::structs::audit::ProofAnnotation;
+use super::types::storage::DataKey;
+
+pub(crate) fn is_auditor(env: &Env, auditor: &Address) -> bool {
+    let key = DataKey::Auditor(auditor.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn set_auditor(env: &Env, auditor: &Address) {
+    let key = DataKey::Auditor(auditor.clone());
+
+    env.storage().persistent().set(&key, &true);
+}
+
+pub(crate) fn remove_auditor(env: &Env, auditor: &Address) {
+    let key = DataKey::Auditor(auditor.clone());
+
+    env.storage().persistent().remove(&key);
+}
+
+pub(crate) fn get_proof_annotations(
+    env: &Env,
+    campaign_id: &String,
+    proof_id: &String,
+) -> Vec<ProofAnnotation> {
+    let key = DataKey::ProofAnnotations(campaign_id.clone(), proof_id.clone());
+
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn set_proof_annotations(
+    env: &Env,
+    campaign_id: &String,
+    proof_id: &String,
+    annotations: &Vec<ProofAnnotation>,
+) {
+    let key = DataKey::ProofAnnotations(campaign_id.clone(), proof_id.clone());
+
+    env.storage().persistent().set(&key, annotations);
+}
+
+pub(crate) fn set_auditor_veto(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AuditorVeto, &enabled);
+}
+
+pub(crate) fn get_auditor_veto(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AuditorVeto)
+        .unwrap_or(false)
+}
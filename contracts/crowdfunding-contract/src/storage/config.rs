@@ -0,0 +1,27 @@
+use crate::storage::{structs::config::Config, types::storage::DataKey};
+use soroban_sdk::{vec, Env, String};
+
+pub(crate) fn set_config(env: &Env, config: &Config) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Get the platform limits, falling back to the defaults until the admin sets them
+pub(crate) fn get_config(env: &Env) -> Config {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .unwrap_or(Config {
+            max_id_length: 64,
+            max_title_length: 100,
+            max_description_length: 1_000,
+            max_uri_length: 256,
+            max_milestones: 20,
+            min_goal: 1,
+            max_goal: i128::MAX,
+            allowed_uri_schemes: vec![
+                env,
+                String::from_str(env, "ipfs://"),
+                String::from_str(env, "ar://"),
+            ],
+        })
+}
@@ -1,6 +1,6 @@
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, Env, String, Vec};
 
-use super::types::storage::DataKey;
+use super::{structs::contributor::ContributorEntry, types::storage::DataKey};
 
 pub(crate) fn has_contribution(env: &Env, campaign_id: &String, contributor: &Address) -> bool {
     let key = DataKey::Contribution(campaign_id.clone(), contributor.clone());
@@ -30,3 +30,146 @@ pub(crate) fn remove_contribution(env: &Env, campaign_id: &String, contributor:
 
     env.storage().persistent().remove(&key);
 }
+
+pub(crate) fn get_contributor_count(env: &Env, campaign_id: &String) -> u32 {
+    get_count(env, List::All, campaign_id)
+}
+
+pub(crate) fn get_contributor(env: &Env, campaign_id: &String, index: u32) -> Address {
+    get_entry(env, List::All, campaign_id, index)
+}
+
+pub(crate) fn add_contributor(env: &Env, campaign_id: &String, contributor: &Address) {
+    add_entry(env, List::All, campaign_id, contributor);
+}
+
+/// Remove a contributor, moving the last one into the freed slot
+pub(crate) fn remove_contributor(env: &Env, campaign_id: &String, contributor: &Address) {
+    remove_entry(env, List::All, campaign_id, contributor);
+}
+
+pub(crate) fn get_public_contributor_count(env: &Env, campaign_id: &String) -> u32 {
+    get_count(env, List::Public, campaign_id)
+}
+
+pub(crate) fn get_public_contributor(env: &Env, campaign_id: &String, index: u32) -> Address {
+    get_entry(env, List::Public, campaign_id, index)
+}
+
+pub(crate) fn is_public_contributor(
+    env: &Env,
+    campaign_id: &String,
+    contributor: &Address,
+) -> bool {
+    let key = index_key(List::Public, campaign_id, contributor);
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn add_public_contributor(env: &Env, campaign_id: &String, contributor: &Address) {
+    add_entry(env, List::Public, campaign_id, contributor);
+}
+
+/// Remove a public contributor, moving the last one into the freed slot
+pub(crate) fn remove_public_contributor(env: &Env, campaign_id: &String, contributor: &Address) {
+    remove_entry(env, List::Public, campaign_id, contributor);
+}
+
+/// Contributor lists kept as a count plus one entry per index
+#[derive(Clone, Copy)]
+enum List {
+    All,    // Every current contributor
+    Public, // Contributors who did not opt out of public listing
+}
+
+fn count_key(list: List, campaign_id: &String) -> DataKey {
+    match list {
+        List::All => DataKey::ContributorCount(campaign_id.clone()),
+        List::Public => DataKey::PublicContributorCount(campaign_id.clone()),
+    }
+}
+
+fn entry_key(list: List, campaign_id: &String, index: u32) -> DataKey {
+    match list {
+        List::All => DataKey::Contributor(campaign_id.clone(), index),
+        List::Public => DataKey::PublicContributor(campaign_id.clone(), index),
+    }
+}
+
+fn index_key(list: List, campaign_id: &String, contributor: &Address) -> DataKey {
+    match list {
+        List::All => DataKey::ContributorIndex(campaign_id.clone(), contributor.clone()),
+        List::Public => DataKey::PublicContributorIndex(campaign_id.clone(), contributor.clone()),
+    }
+}
+
+fn get_count(env: &Env, list: List, campaign_id: &String) -> u32 {
+    let key = count_key(list, campaign_id);
+
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+fn set_count(env: &Env, list: List, campaign_id: &String, count: u32) {
+    let key = count_key(list, campaign_id);
+
+    env.storage().persistent().set(&key, &count);
+}
+
+fn get_entry(env: &Env, list: List, campaign_id: &String, index: u32) -> Address {
+    let key = entry_key(list, campaign_id, index);
+
+    env.storage().persistent().get(&key).unwrap()
+}
+
+fn add_entry(env: &Env, list: List, campaign_id: &String, contributor: &Address) {
+    let contributor_key = index_key(list, campaign_id, contributor);
+    if env.storage().persistent().has(&contributor_key) {
+        return;
+    }
+
+    let index = get_count(env, list, campaign_id);
+    let key = entry_key(list, campaign_id, index);
+    env.storage().persistent().set(&key, contributor);
+    env.storage().persistent().set(&contributor_key, &index);
+    set_count(env, list, campaign_id, index + 1);
+}
+
+fn remove_entry(env: &Env, list: List, campaign_id: &String, contributor: &Address) {
+    let contributor_key = index_key(list, campaign_id, contributor);
+    let Some(index) = env.storage().persistent().get::<_, u32>(&contributor_key) else {
+        return;
+    };
+
+    let last = get_count(env, list, campaign_id) - 1;
+    if index != last {
+        let moved = get_entry(env, list, campaign_id, last);
+        let key = entry_key(list, campaign_id, index);
+        env.storage().persistent().set(&key, &moved);
+        let moved_key = index_key(list, campaign_id, &moved);
+        env.storage().persistent().set(&moved_key, &index);
+    }
+
+    let key = entry_key(list, campaign_id, last);
+    env.storage().persistent().remove(&key);
+    env.storage().persistent().remove(&contributor_key);
+    set_count(env, list, campaign_id, last);
+}
+
+pub(crate) fn get_leaderboard(env: &Env, campaign_id: &String) -> Vec<ContributorEntry> {
+    let key = DataKey::Leaderboard(campaign_id.clone());
+
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn set_leaderboard(
+    env: &Env,
+    campaign_id: &String,
+    leaderboard: &Vec<ContributorEntry>,
+) {
+    let key = DataKey::Leaderboard(campaign_id.clone());
+
+    env.storage().persistent().set(&key, leaderboard);
+}
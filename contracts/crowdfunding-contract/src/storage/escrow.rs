@@ -0,0 +1,15 @@
+use crate::storage::types::storage::DataKey;
+use soroban_sdk::Env;
+
+pub(crate) fn set_liabilities(env: &Env, liabilities: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Liabilities, &liabilities);
+}
+
+pub(crate) fn get_liabilities(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Liabilities)
+        .unwrap_or(0)
+}
@@ -0,0 +1,39 @@
+use crate::storage::{
+    structs::fraud::FraudFinding,
+    types::{error::Error, storage::DataKey},
+};
+use soroban_sdk::{Address, Env, String, Vec};
+
+pub(crate) fn fraud_finding_key(campaign_id: &String) -> DataKey {
+    DataKey::FraudFinding(campaign_id.clone())
+}
+
+pub(crate) fn set_fraud_finding(env: &Env, campaign_id: &String, finding: &FraudFinding) {
+    let key = fraud_finding_key(campaign_id);
+    env.storage().persistent().set(&key, finding);
+}
+
+pub(crate) fn get_fraud_finding(env: &Env, campaign_id: &String) -> Result<FraudFinding, Error> {
+    let key = fraud_finding_key(campaign_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::InvalidCampaignStatus)
+}
+
+pub(crate) fn get_creator_findings(env: &Env, creator: &Address) -> Vec<String> {
+    let key = DataKey::CreatorFraudFindings(creator.clone());
+
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_creator_finding(env: &Env, creator: &Address, campaign_id: &String) {
+    let key = DataKey::CreatorFraudFindings(creator.clone());
+    let mut campaign_ids = get_creator_findings(env, creator);
+
+    campaign_ids.push_back(campaign_id.clone());
+    env.storage().persistent().set(&key, &campaign_ids);
+}
@@ -25,6 +25,7 @@ pub(crate) fn get_milestone(
         .ok_or(Error::MilestoneNotFound)
 }
 
+#[allow(dead_code)]
 pub(crate) fn has_milestone(env: &Env, campaign_id: &String, sequence: u32) -> bool {
     let key = milestone_key(campaign_id, sequence);
     env.storage().persistent().has(&key)
@@ -1,8 +1,23 @@
 pub mod admin;
+pub mod amendment;
+pub mod auditor;
 pub mod campaign;
+pub mod config;
 pub mod contribution;
+pub mod escrow;
+pub mod fraud;
 pub mod milestone;
+pub mod moderator;
+pub mod organization;
+pub mod portfolio;
 pub mod proof;
+pub mod report;
+pub mod reputation;
+pub mod review;
+pub mod round;
+pub mod settlement;
+pub mod statement;
+pub mod stats;
 pub mod structs;
 pub mod token;
 pub mod types;
@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env};
+
+use super::types::storage::DataKey;
+
+pub(crate) fn is_moderator(env: &Env, moderator: &Address) -> bool {
+    let key = DataKey::Moderator(moderator.clone());
+
+    env.storage().persistent().has(&key)
+}
+
+pub(crate) fn set_moderator(env: &Env, moderator: &Address) {
+    let key = DataKey::Moderator(moderator.clone());
+
+    env.storage().persistent().set(&key, &true);
+}
+
+pub(crate) fn remove_moderator(env: &Env, moderator: &Address) {
+    let key = DataKey::Moderator(moderator.clone());
+
+    env.storage().persistent().remove(&key);
+}
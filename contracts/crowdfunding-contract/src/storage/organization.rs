@@ -0,0 +1,46 @@
+use crate::storage::{
+    structs::organization::Organization,
+    types::{error::Error, storage::DataKey},
+};
+use soroban_sdk::{Env, String, Vec};
+
+pub(crate) fn organization_key(organization_id: u32) -> DataKey {
+    DataKey::Organization(organization_id)
+}
+
+pub(crate) fn set_organization(env: &Env, organization_id: u32, organization: &Organization) {
+    let key = organization_key(organization_id);
+    env.storage().persistent().set(&key, organization);
+}
+
+pub(crate) fn get_organization(env: &Env, organization_id: u32) -> Result<Organization, Error> {
+    let key = organization_key(organization_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::OrganizationNotFound)
+}
+
+pub(crate) fn next_organization_id(env: &Env) -> u32 {
+    let key = DataKey::OrganizationCount;
+    let organization_id: u32 = env.storage().instance().get(&key).unwrap_or(0) + 1;
+
+    env.storage().instance().set(&key, &organization_id);
+    organization_id
+}
+
+pub(crate) fn get_organization_campaigns(env: &Env, organization_id: u32) -> Vec<String> {
+    let key = DataKey::OrganizationCampaigns(organization_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_organization_campaign(env: &Env, organization_id: u32, campaign_id: &String) {
+    let key = DataKey::OrganizationCampaigns(organization_id);
+    let mut campaigns = get_organization_campaigns(env, organization_id);
+
+    campaigns.push_back(campaign_id.clone());
+    env.storage().persistent().set(&key, &campaigns);
+}
@@ -0,0 +1,46 @@
+use crate::storage::{structs::portfolio::DonorPosition, types::storage::DataKey};
+use soroban_sdk::{Address, Env, String, Vec};
+
+pub(crate) fn get_donor_campaigns(env: &Env, donor: &Address) -> Vec<String> {
+    let key = DataKey::DonorCampaigns(donor.clone());
+
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_donor_campaign(env: &Env, donor: &Address, campaign_id: &String) {
+    let key = DataKey::DonorCampaigns(donor.clone());
+    let mut campaign_ids = get_donor_campaigns(env, donor);
+
+    if !campaign_ids.contains(campaign_id) {
+        campaign_ids.push_back(campaign_id.clone());
+        env.storage().persistent().set(&key, &campaign_ids);
+    }
+}
+
+pub(crate) fn set_donor_position(
+    env: &Env,
+    campaign_id: &String,
+    donor: &Address,
+    position: &DonorPosition,
+) {
+    let key = DataKey::DonorPosition(campaign_id.clone(), donor.clone());
+    env.storage().persistent().set(&key, position);
+}
+
+pub(crate) fn get_donor_position(
+    env: &Env,
+    campaign_id: &String,
+    donor: &Address,
+) -> DonorPosition {
+    let key = DataKey::DonorPosition(campaign_id.clone(), donor.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(DonorPosition {
+            contributed: 0,
+            refunded: 0,
+        })
+}
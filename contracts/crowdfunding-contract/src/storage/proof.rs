@@ -2,7 +2,7 @@ use crate::storage::{
     structs::proof::Proof,
     types::{error::Error, storage::DataKey},
 };
-use soroban_sdk::{Env, String};
+use soroban_sdk::{Env, String, Vec};
 
 pub(crate) fn proof_key(campaign_id: &String, proof_id: &String) -> DataKey {
     DataKey::Proof(campaign_id.clone(), proof_id.clone())
@@ -25,12 +25,33 @@ pub(crate) fn get_proof(
         .ok_or(Error::ProofNotFound)
 }
 
+#[allow(dead_code)]
 pub(crate) fn has_proof(env: &Env, campaign_id: &String, proof_id: &String) -> bool {
     let key = proof_key(campaign_id, proof_id);
     env.storage().persistent().has(&key)
 }
 
+#[allow(dead_code)]
 pub(crate) fn remove_proof(env: &Env, campaign_id: &String, proof_id: &String) {
     let key = proof_key(campaign_id, proof_id);
     env.storage().persistent().remove(&key);
 }
+
+pub(crate) fn get_campaign_proofs(env: &Env, campaign_id: &String) -> Vec<String> {
+    let key = DataKey::CampaignProofs(campaign_id.clone());
+
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_campaign_proof(env: &Env, campaign_id: &String, proof_id: &String) {
+    let key = DataKey::CampaignProofs(campaign_id.clone());
+    let mut proof_ids = get_campaign_proofs(env, campaign_id);
+
+    if !proof_ids.contains(proof_id) {
+        proof_ids.push_back(proof_id.clone());
+        env.storage().persistent().set(&key, &proof_ids);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::storage::{
+    structs::report::{CampaignReports, ReportStatus},
+    types::storage::DataKey,
+};
+use soroban_sdk::{Env, String, Vec};
+
+/// Share of donations, in basis points, whose reports trigger a review by default
+const DEFAULT_REPORT_THRESHOLD_BPS: u32 = 2_000;
+
+pub(crate) fn reports_key(campaign_id: &String) -> DataKey {
+    DataKey::CampaignReports(campaign_id.clone())
+}
+
+pub(crate) fn set_campaign_reports(env: &Env, campaign_id: &String, reports: &CampaignReports) {
+    let key = reports_key(campaign_id);
+    env.storage().persistent().set(&key, reports);
+}
+
+pub(crate) fn get_campaign_reports(env: &Env, campaign_id: &String) -> CampaignReports {
+    let key = reports_key(campaign_id);
+
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(CampaignReports {
+            campaign_id: campaign_id.clone(),
+            reports: Vec::new(env),
+            pending_weight: 0,
+            pending_from: 0,
+            status: ReportStatus::Open,
+            resolved_by: None,
+            resolution: String::from_str(env, ""),
+        })
+}
+
+pub(crate) fn set_report_threshold(env: &Env, threshold_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReportThreshold, &threshold_bps);
+}
+
+pub(crate) fn get_report_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReportThreshold)
+        .unwrap_or(DEFAULT_REPORT_THRESHOLD_BPS)
+}
+
+pub(crate) fn get_report_queue(env: &Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReportQueue)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn enqueue_report_review(env: &Env, campaign_id: &String) {
+    let mut queue = get_report_queue(env);
+
+    if !queue.contains(campaign_id) {
+        queue.push_back(campaign_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReportQueue, &queue);
+    }
+}
+
+pub(crate) fn dequeue_report_review(env: &Env, campaign_id: &String) {
+    let mut queue = get_report_queue(env);
+
+    if let Some(index) = queue.first_index_of(campaign_id) {
+        queue.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReportQueue, &queue);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::storage::{structs::reputation::Reputation, types::storage::DataKey};
+use soroban_sdk::{Address, Env, String};
+
+pub(crate) fn get_reputation(env: &Env, creator: &Address) -> Reputation {
+    let key = DataKey::Reputation(creator.clone());
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+pub(crate) fn set_reputation(env: &Env, creator: &Address, reputation: &Reputation) {
+    let key = DataKey::Reputation(creator.clone());
+    env.storage().persistent().set(&key, reputation);
+}
+
+pub(crate) fn get_rating(env: &Env, campaign_id: &String, donor: &Address) -> Option<u32> {
+    let key = DataKey::Rating(campaign_id.clone(), donor.clone());
+    env.storage().persistent().get(&key)
+}
+
+pub(crate) fn set_rating(env: &Env, campaign_id: &String, donor: &Address, rating: u32) {
+    let key = DataKey::Rating(campaign_id.clone(), donor.clone());
+    env.storage().persistent().set(&key, &rating);
+}
@@ -0,0 +1,18 @@
+use crate::storage::{structs::review::ReviewEntry, types::storage::DataKey};
+use soroban_sdk::{Env, String, Vec};
+
+pub(crate) fn get_reviews(env: &Env, campaign_id: &String) -> Vec<ReviewEntry> {
+    let key = DataKey::CampaignReviews(campaign_id.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_review(env: &Env, campaign_id: &String, review: &ReviewEntry) {
+    let key = DataKey::CampaignReviews(campaign_id.clone());
+    let mut reviews = get_reviews(env, campaign_id);
+
+    reviews.push_back(review.clone());
+    env.storage().persistent().set(&key, &reviews);
+}
@@ -0,0 +1,45 @@
+use crate::storage::{
+    structs::round::Round,
+    types::{error::Error, storage::DataKey},
+};
+use soroban_sdk::{Env, String, Vec};
+
+pub(crate) fn round_key(round_id: u32) -> DataKey {
+    DataKey::Round(round_id)
+}
+
+pub(crate) fn set_round(env: &Env, round_id: u32, round: &Round) {
+    let key = round_key(round_id);
+    env.storage().persistent().set(&key, round);
+}
+
+pub(crate) fn get_round(env: &Env, round_id: u32) -> Result<Round, Error> {
+    let key = round_key(round_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::RoundNotFound)
+}
+
+pub(crate) fn next_round_id(env: &Env) -> u32 {
+    let key = DataKey::RoundCount;
+    let round_id: u32 = env.storage().instance().get(&key).unwrap_or(0) + 1;
+
+    env.storage().instance().set(&key, &round_id);
+    round_id
+}
+
+pub(crate) fn get_campaign_rounds(env: &Env, campaign_id: &String) -> Vec<u32> {
+    let key = DataKey::CampaignRounds(campaign_id.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_campaign_round(env: &Env, campaign_id: &String, round_id: u32) {
+    let key = DataKey::CampaignRounds(campaign_id.clone());
+    let mut round_ids = get_campaign_rounds(env, campaign_id);
+    round_ids.push_back(round_id);
+    env.storage().persistent().set(&key, &round_ids);
+}
@@ -0,0 +1,27 @@
+use crate::storage::{
+    structs::settlement::CampaignSettlement,
+    types::{error::Error, storage::DataKey},
+};
+use soroban_sdk::{Env, String};
+
+pub(crate) fn settlement_key(campaign_id: &String) -> DataKey {
+    DataKey::Settlement(campaign_id.clone())
+}
+
+pub(crate) fn set_settlement(env: &Env, campaign_id: &String, settlement: &CampaignSettlement) {
+    let key = settlement_key(campaign_id);
+    env.storage().persistent().set(&key, settlement);
+}
+
+pub(crate) fn get_settlement(env: &Env, campaign_id: &String) -> Result<CampaignSettlement, Error> {
+    let key = settlement_key(campaign_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::SettlementNotFound)
+}
+
+pub(crate) fn has_settlement(env: &Env, campaign_id: &String) -> bool {
+    let key = settlement_key(campaign_id);
+    env.storage().persistent().has(&key)
+}
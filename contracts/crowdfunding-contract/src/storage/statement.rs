@@ -0,0 +1,16 @@
+use crate::storage::{structs::statement::GivingLine, types::storage::DataKey};
+use soroban_sdk::{Address, Env, Vec};
+
+pub(crate) fn get_giving_lines(env: &Env, donor: &Address, year: u32) -> Vec<GivingLine> {
+    let key = DataKey::GivingLines(donor.clone(), year);
+
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn set_giving_lines(env: &Env, donor: &Address, year: u32, lines: &Vec<GivingLine>) {
+    let key = DataKey::GivingLines(donor.clone(), year);
+    env.storage().persistent().set(&key, lines);
+}
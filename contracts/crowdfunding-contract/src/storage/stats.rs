@@ -0,0 +1,23 @@
+use crate::storage::{structs::stats::CampaignTally, types::storage::DataKey};
+use soroban_sdk::{Env, String, Vec};
+
+pub(crate) fn set_campaign_tally(env: &Env, campaign_id: &String, tally: &CampaignTally) {
+    let key = DataKey::CampaignTally(campaign_id.clone());
+    env.storage().persistent().set(&key, tally);
+}
+
+pub(crate) fn get_campaign_tally(env: &Env, campaign_id: &String) -> CampaignTally {
+    let key = DataKey::CampaignTally(campaign_id.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(CampaignTally {
+            contributions: 0,
+            gifts_amount: 0,
+            escrowed_amount: 0,
+            released_amount: 0,
+            refunded_amount: 0,
+            clawed_back_amount: 0,
+            gift_buckets: Vec::new(env),
+        })
+}
@@ -0,0 +1,16 @@
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+use super::milestone::MilestoneDraft;
+
+/// Pending change to the uncompleted part of a locked milestone plan
+#[derive(Clone)]
+#[contracttype]
+pub struct MilestoneAmendment {
+    pub campaign_id: String,
+    pub first_sequence: u32,       // First milestone replaced by the plan
+    pub plan: Vec<MilestoneDraft>, // Replacement for milestones first_sequence..
+    pub reason: String,            // Why the creator is changing the plan
+    pub proposed_at: u64,
+    pub approvals: Vec<Address>, // Donors who approved the amendment
+    pub approved_weight: i128,   // Approving donors' current contributions at the last vote
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{contracttype, Address, BytesN, String};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AuditVerdict {
+    Accept,
+    Concern, // Evidence is plausible but raises questions
+    Reject,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ProofAnnotation {
+    pub auditor: Address,
+    pub verdict: AuditVerdict,
+    pub comment_uri: String, // Where the auditor's full opinion is published
+    pub content_hash: BytesN<32>, // Hash of the published comment
+    pub timestamp: u64,
+}
@@ -0,0 +1,53 @@
+use soroban_sdk::{contracttype, String, Vec};
+
+/// Planned use of part of a milestone's tranche
+#[derive(Clone)]
+#[contracttype]
+pub struct BudgetLineItem {
+    pub category: String, // e.g. "materials", "salaries", "logistics"
+    pub planned_amount: i128,
+    pub description: String,
+}
+
+/// Actual spend reported in a proof against one budget line item
+#[derive(Clone)]
+#[contracttype]
+pub struct SpendReport {
+    pub line_item: u32, // Index in the validated milestone's budget
+    pub actual_amount: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct LineItemVariance {
+    pub line_item: u32,
+    pub category: String,
+    pub planned_amount: i128,
+    pub actual_amount: i128,
+    pub variance: i128, // actual - planned, positive when overspent
+    pub overrun: bool,  // Actual spend above planned plus the threshold
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct MilestoneVariance {
+    pub sequence: u32,
+    pub reported: bool, // Whether a validated proof reported spend yet
+    pub planned_amount: i128,
+    pub actual_amount: i128,
+    pub variance: i128,
+    pub overrun: bool,
+    pub line_items: Vec<LineItemVariance>,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct BudgetVariance {
+    pub campaign_id: String,
+    pub threshold_bps: u32, // Tolerated overspend, in basis points of the plan
+    pub planned_amount: i128,
+    pub actual_amount: i128,
+    pub variance: i128,
+    pub overrun: bool,
+    pub milestones: Vec<MilestoneVariance>,
+}
@@ -1,19 +1,44 @@
 use soroban_sdk::{contracttype, Address, String};
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CampaignStatus {
+    PendingReview,    // Awaiting a moderator decision
+    ChangesRequested, // Moderator asked the creator to revise the campaign
+    Approved,         // Live and accepting donations
+    Rejected,         // Permanently refused by a moderator
+    Cancelled,        // Withdrawn by the creator, contributions can be refunded
+    Completed,        // Every milestone paid out and leftover escrow settled
+    Frozen,           // Frozen by the admin over fraud, escrow is clawed back
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct Campaign {
     pub id: String, // Campaign identifier
     pub creator: Address,
-    pub title: String,       // Campaign title
-    pub description: String, // Campaign description
+    pub organization_id: Option<u32>, // Organisation the campaign belongs to
+    pub fallback_beneficiary: Option<Address>, // Receives leftover escrow when finalized
+    pub title: String,                // Campaign title
+    pub description: String,          // Campaign description
+    pub status: CampaignStatus,
     pub goal: i128,
     pub min_donation: i128,
+    /// Contributions still counted plus matching funds. A refund or clawback
+    /// claim removes the whole contribution even when the escrow can only pay
+    /// part of it back, so payouts are always worked out from the escrow in
+    /// `CampaignTally::escrowed_amount`, never from this total.
     pub total_raised: i128,
     pub supporters: u32,
+    pub matched_amount: i128, // Matching funds credited from rounds
+    pub created_at: u64,
+    pub cancelled_at: Option<u64>, // Vesting stops when the campaign is cancelled
 
     // Milestone Management
     pub milestones_count: u32,     // Total milestones for this campaign
+    pub milestones_locked: bool,   // Plan locked since the first contribution
     pub current_milestone: u32,    // Latest completed milestone (0 = none)
-    pub withdrawable_amount: i128, // Amount available for withdrawal
+    pub withdrawable_amount: i128, // Validated tranches not withdrawn yet, vested or not
+    pub release_delay: u64,        // Seconds between validation and payout
+    pub withdrawals_paused: bool,  // Paused while donor reports are under review
 }
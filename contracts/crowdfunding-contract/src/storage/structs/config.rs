@@ -0,0 +1,15 @@
+use soroban_sdk::{contracttype, String, Vec};
+
+/// Platform limits on campaign, milestone and proof inputs
+#[derive(Clone)]
+#[contracttype]
+pub struct Config {
+    pub max_id_length: u32, // Campaign and proof identifiers
+    pub max_title_length: u32,
+    pub max_description_length: u32, // Campaign, milestone and proof descriptions
+    pub max_uri_length: u32,
+    pub max_milestones: u32, // Milestones per campaign
+    pub min_goal: i128,
+    pub max_goal: i128,
+    pub allowed_uri_schemes: Vec<String>, // Proof URI prefixes, e.g. "ipfs://"
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, Address};
+
+/// A publicly listed contributor and their current contribution
+#[derive(Clone)]
+#[contracttype]
+pub struct ContributorEntry {
+    pub contributor: Address,
+    pub amount: i128,
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::contracttype;
+
+/// Token balance held by the contract against what it owes
+#[derive(Clone)]
+#[contracttype]
+pub struct Reconciliation {
+    pub balance: i128,     // Contract token balance
+    pub liabilities: i128, // Escrow each campaign still holds plus open round pools
+    pub surplus: i128,     // Balance above liabilities, negative on a shortfall
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::{contracttype, Vec};
+
+use super::{campaign::Campaign, milestone::Milestone, proof::Proof};
+
+/// Contribution figures of a campaign in an export bundle
+#[derive(Clone)]
+#[contracttype]
+pub struct ContributionSummary {
+    pub supporters: u32,
+    pub contributed_amount: i128, // Raised from donors, without matching funds
+    pub matched_amount: i128,
+    pub released_amount: i128, // Withdrawn by the creator through milestones
+    pub withdrawable_amount: i128,
+}
+
+/// One page of a campaign's complete record, for transparency reports
+///
+/// Pages walk the campaign's milestones in sequence order and then its proofs
+/// in submission order. The campaign and its contribution summary are repeated
+/// on every page so each page can be read on its own.
+#[derive(Clone)]
+#[contracttype]
+pub struct CampaignExport {
+    pub format_version: u32, // Bumped whenever the bundle layout changes
+    pub campaign: Campaign,
+    pub contributions: ContributionSummary,
+    pub milestones: Vec<Milestone>,
+    pub proofs: Vec<Proof>,
+    pub next_cursor: Option<u32>, // None on the last page
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{contracttype, Address, BytesN, String};
+
+/// Public record of fraud found in a frozen campaign
+#[derive(Clone)]
+#[contracttype]
+pub struct FraudFinding {
+    pub campaign_id: String,
+    pub creator: Address,
+    pub evidence_uri: String,      // Where the evidence can be reviewed
+    pub evidence_hash: BytesN<32>, // Hash of the evidence document
+    pub recoverable_amount: i128,  // Escrow not paid out when the campaign was frozen
+    pub contributed_amount: i128,  // Donor contributions the escrow is split across
+    pub refunded_amount: i128,     // Claimed by contributors so far
+    pub found_at: u64,
+}
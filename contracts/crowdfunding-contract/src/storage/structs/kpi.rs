@@ -0,0 +1,37 @@
+use soroban_sdk::{contracttype, String, Vec};
+
+/// Measurable impact a milestone commits to, e.g. 1000 "meals served"
+#[derive(Clone)]
+#[contracttype]
+pub struct Kpi {
+    pub name: String, // e.g. "meals served"
+    pub unit: String, // e.g. "meals"
+    pub target: i128,
+}
+
+/// Value reported in a proof for one of the milestone's KPIs
+#[derive(Clone)]
+#[contracttype]
+pub struct KpiReport {
+    pub kpi: u32, // Index in the validated milestone's KPIs
+    pub value: i128,
+}
+
+/// Achieved-versus-target for one KPI, summed across a campaign's milestones
+#[derive(Clone)]
+#[contracttype]
+pub struct KpiImpact {
+    pub name: String,
+    pub unit: String,
+    pub target: i128,      // Sum of targets over all milestones
+    pub achieved: i128,    // Sum of values attested on validated milestones
+    pub progress_bps: u32, // achieved / target in basis points, capped at 10000
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CampaignImpact {
+    pub campaign_id: String,
+    pub milestones_completed: u32,
+    pub kpis: Vec<KpiImpact>,
+}
@@ -1,15 +1,51 @@
-use soroban_sdk::{contracttype, String};
+use super::{budget::BudgetLineItem, kpi::Kpi};
+use soroban_sdk::{contracttype, String, Vec};
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Milestone {
     pub campaign_id: String,
-    pub sequence: u32,             // 1, 2, 3... (order matters)
-    pub target_amount: i128,       // Funding needed to reach this milestone
-    pub description: String,       // What this milestone represents
-    pub completed: bool,           // Has this milestone been validated?
-    pub proof_id: Option<String>,  // Which proof validated this milestone
-    pub completed_at: Option<u64>, // When was it completed
+    pub sequence: u32,               // 1, 2, 3... (order matters)
+    pub target_amount: i128,         // Funding needed to reach this milestone
+    pub description: String,         // What this milestone represents
+    pub due_date: Option<u64>,       // Deadline for validation, if any
+    pub budget: Vec<BudgetLineItem>, // Planned use of this milestone's tranche
+    pub kpis: Vec<Kpi>,              // Impact this milestone commits to
+    pub kpi_values: Vec<i128>,       // Values attested on validation, one per KPI
+    pub completed: bool,             // Has this milestone been validated?
+    pub proof_id: Option<String>,    // Which proof validated this milestone
+    pub completed_at: Option<u64>,   // When was it completed
+    pub vesting: Vesting,            // How the tranche is released once validated
+    pub claimed_amount: i128,        // Part of the tranche already withdrawn
+    pub unlocks_at: Option<u64>,     // Release timelock set on validation
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum Vesting {
+    Immediate,               // The whole tranche is released on validation
+    Linear(VestingSchedule), // The tranche vests linearly over the schedule
+}
+
+/// Linear release of a milestone's tranche once validated
+#[derive(Clone)]
+#[contracttype]
+pub struct VestingSchedule {
+    pub start: u64,         // Nothing vests before this time
+    pub end: u64,           // The whole tranche has vested at this time
+    pub cliff: Option<u64>, // Nothing can be withdrawn before this time
+}
+
+/// Editable description of a milestone, used when replacing a campaign's plan
+#[derive(Clone)]
+#[contracttype]
+pub struct MilestoneDraft {
+    pub target_amount: i128,
+    pub description: String,
+    pub due_date: Option<u64>,
+    pub budget: Vec<BudgetLineItem>,
+    pub kpis: Vec<Kpi>,
+    pub vesting: Vesting,
 }
 
 #[derive(Clone)]
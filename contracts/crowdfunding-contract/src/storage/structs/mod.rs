@@ -1,4 +1,23 @@
+pub mod amendment;
+pub mod audit;
+pub mod budget;
 pub mod campaign;
+pub mod config;
 pub mod contribution;
+pub mod contributor;
+pub mod escrow;
+pub mod export;
+pub mod fraud;
+pub mod kpi;
 pub mod milestone;
+pub mod organization;
+pub mod portfolio;
+pub mod preview;
 pub mod proof;
+pub mod report;
+pub mod reputation;
+pub mod review;
+pub mod round;
+pub mod settlement;
+pub mod statement;
+pub mod stats;
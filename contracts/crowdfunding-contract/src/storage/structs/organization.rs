@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum VerificationLevel {
+    Unverified, // Self-registered, nothing checked yet
+    Basic,      // Registration number checked by a moderator
+    Verified,   // Registration, website and controllers checked by a moderator
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Organization {
+    pub id: u32,
+    pub name: String,
+    pub registration_hash: BytesN<32>, // Hash of the official registration number
+    pub website: String,
+    pub controllers: Vec<Address>, // Addresses allowed to act for the organisation
+    pub verification: VerificationLevel,
+    pub total_raised: i128,   // Net funds raised across all linked campaigns
+    pub total_released: i128, // Funds paid out across all linked campaigns
+    pub created_at: u64,
+}
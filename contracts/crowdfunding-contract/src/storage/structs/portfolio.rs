@@ -0,0 +1,22 @@
+use soroban_sdk::{contracttype, String};
+
+use super::campaign::CampaignStatus;
+
+/// Lifetime totals of one donor in one campaign
+#[derive(Clone)]
+#[contracttype]
+pub struct DonorPosition {
+    pub contributed: i128, // Everything the donor gave, refunded gifts included
+    pub refunded: i128,    // Refunds, leftover returns and clawback shares received
+}
+
+/// One campaign on a donor's account page
+#[derive(Clone)]
+#[contracttype]
+pub struct PortfolioEntry {
+    pub campaign_id: String,
+    pub status: CampaignStatus, // Completed once the campaign has been archived
+    pub contributed: i128,
+    pub refunded: i128,
+    pub claimable: i128, // What a refund or clawback claim would pay right now
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{contracttype, Vec};
+
+/// Outcome of a contribution if it were made now
+#[derive(Clone)]
+#[contracttype]
+pub struct ContributePreview {
+    pub amount: i128,         // Credited to the campaign, before any token transfer fee
+    pub total_raised: i128,   // Campaign total after the contribution
+    pub remaining_goal: i128, // Still needed to reach the goal afterwards
+}
+
+/// Outcome of a refund if it were claimed now
+#[derive(Clone)]
+#[contracttype]
+pub struct RefundPreview {
+    pub amount: i128,       // Paid back to the contributor
+    pub total_raised: i128, // Campaign total after the refund
+    pub supporters: u32,    // Campaign supporters after the refund
+}
+
+/// Outcome of a milestone withdrawal if it were made now
+#[derive(Clone)]
+#[contracttype]
+pub struct WithdrawPreview {
+    pub amount: i128,              // Paid to the creator
+    pub withdrawable_amount: i128, // Validated tranches left after the withdrawal
+    pub milestones: Vec<u32>,      // Milestones whose tranche is paid from
+}
@@ -1,11 +1,16 @@
-use soroban_sdk::{contracttype, String};
+use super::{budget::SpendReport, kpi::KpiReport};
+use soroban_sdk::{contracttype, String, Vec};
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Proof {
-    pub id: String,          // Proof identifier
-    pub campaign_id: String, // Which campaign this proof belongs to
-    pub uri: String,         // IPFS or external URI
-    pub description: String, // Description of the proof
-    pub timestamp: u64,      // When proof was submitted
+    pub id: String,                 // Proof identifier
+    pub campaign_id: String,        // Which campaign this proof belongs to
+    pub uri: String,                // IPFS or external URI
+    pub description: String,        // Description of the proof
+    pub timestamp: u64,             // When proof was submitted
+    pub spend: Vec<SpendReport>,    // Actual spend against the milestone budget
+    pub kpi_values: Vec<KpiReport>, // Impact reported for the milestone's KPIs
+    pub revoked_at: Option<u64>,    // Set once the admin revokes the proof
+    pub revocation_reason: Option<String>,
 }
@@ -0,0 +1,41 @@
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ReportCategory {
+    MisuseOfFunds,
+    FalseClaims, // Campaign or proofs misrepresent the project
+    FakeProof,
+    Other,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ReportStatus {
+    Open,        // Reports below the threshold, withdrawals unaffected
+    UnderReview, // Threshold crossed, withdrawals paused until a moderator decides
+    Dismissed,   // Moderator found no wrongdoing, withdrawals resumed
+    Upheld,      // Moderator confirmed the concerns, withdrawals stay paused
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct FraudReport {
+    pub reporter: Address,
+    pub category: ReportCategory,
+    pub evidence_uri: String,
+    pub weight: i128, // Reporter's contribution when the report was filed, 0 once refunded
+    pub reported_at: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CampaignReports {
+    pub campaign_id: String,
+    pub reports: Vec<FraudReport>,
+    pub pending_weight: i128, // Weight of the reports filed since the last decision
+    pub pending_from: u32,    // Index of the first report filed since the last decision
+    pub status: ReportStatus,
+    pub resolved_by: Option<Address>, // Moderator behind the latest decision
+    pub resolution: String,           // Reason given for the latest decision
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct Reputation {
+    pub milestones_on_time: u32,  // Validated on or before their due date
+    pub milestones_late: u32,     // Validated after their due date
+    pub milestones_rejected: u32, // Proofs rejected by the admin
+    pub campaigns_cancelled: u32,
+    pub challenges_upheld: u32, // Challenges against the creator upheld by moderators
+    pub fraud_findings: u32,    // Campaigns frozen by the admin over fraud
+    pub fraud_flags: u32,       // Proofs revoked after the funds they unlocked were paid
+    pub ratings_count: u32,
+    pub ratings_total: u32, // Sum of all donor ratings (1-5 each)
+}
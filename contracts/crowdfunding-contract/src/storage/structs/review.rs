@@ -0,0 +1,19 @@
+use soroban_sdk::{contracttype, Address, String};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ReviewAction {
+    Approved,
+    Rejected,
+    ChangesRequested,
+    Resubmitted, // Creator revised the campaign after a change request
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ReviewEntry {
+    pub reviewer: Address, // Moderator, or the creator on resubmission
+    pub action: ReviewAction,
+    pub reason: String,
+    pub timestamp: u64,
+}
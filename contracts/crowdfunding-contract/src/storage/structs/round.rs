@@ -0,0 +1,25 @@
+use soroban_sdk::{contracttype, Address, Map, String, Vec};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum RoundStatus {
+    Open,      // Accepting enrolments and matching funds
+    Finalized, // Matching pool distributed, round closed
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Round {
+    pub id: u32,
+    pub name: String,
+    pub status: RoundStatus,
+    pub campaigns: Vec<String>,      // Campaigns enrolled in the round
+    pub matching_pool: i128,         // Total matching funds deposited
+    pub funders: Map<Address, i128>, // funder -> amount deposited
+    pub distributed: i128,           // Amount credited to campaigns on finalization
+    pub matches: Map<String, i128>,  // campaign_id -> matched amount
+    pub returned: i128,              // Matched funds handed back by closed campaigns
+    pub claimed: Map<Address, i128>, // funder -> returned funds claimed
+    pub created_at: u64,
+    pub finalized_at: Option<u64>,
+}
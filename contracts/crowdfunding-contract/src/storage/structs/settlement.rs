@@ -0,0 +1,22 @@
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+/// Compact archive of a closed campaign
+///
+/// Keeps the figures and references needed to audit the campaign after its
+/// bulky fields (title, description) have been pruned from chain state.
+#[derive(Clone)]
+#[contracttype]
+pub struct CampaignSettlement {
+    pub campaign_id: String,
+    pub creator: Address,
+    pub payout_address: Address, // Where released funds were paid
+    pub total_raised: i128,
+    pub supporters: u32,
+    pub released_amount: i128,               // Paid to the creator
+    pub leftover_amount: i128,               // Escrow left for donors after the final milestone
+    pub leftover_recipient: Option<Address>, // Fallback beneficiary, None when claimed pro rata
+    pub created_at: u64,
+    pub settled_at: u64,
+    pub proof_ids: Vec<String>,
+    pub milestones: Vec<u32>, // Sequences of the campaign's milestones
+}
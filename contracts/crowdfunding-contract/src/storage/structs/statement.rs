@@ -0,0 +1,33 @@
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+/// A donor's giving to one campaign within one calendar year
+#[derive(Clone)]
+#[contracttype]
+pub struct GivingLine {
+    pub campaign_id: String,
+    pub organization_id: Option<u32>, // Organisation the campaign belonged to
+    pub contributed: i128,            // Gifts made during the year
+    pub refunded: i128,               // Funds returned during the year, whenever given
+    pub net: i128,                    // contributed - refunded
+}
+
+/// Net giving to one organisation within the statement year
+#[derive(Clone)]
+#[contracttype]
+pub struct OrganizationTotal {
+    pub organization_id: u32,
+    pub net: i128,
+}
+
+/// Yearly donation statement for tax receipts
+#[derive(Clone)]
+#[contracttype]
+pub struct GivingStatement {
+    pub donor: Address,
+    pub year: u32, // UTC calendar year of the ledger timestamps
+    pub lines: Vec<GivingLine>,
+    pub organizations: Vec<OrganizationTotal>,
+    pub total_contributed: i128,
+    pub total_refunded: i128,
+    pub total_net: i128,
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{contracttype, Vec};
+
+/// Running totals of a campaign, updated as funds move
+#[derive(Clone)]
+#[contracttype]
+pub struct CampaignTally {
+    pub contributions: u32,       // Every contribution, repeat gifts included
+    pub gifts_amount: i128,       // Sum of all gifts received
+    pub escrowed_amount: i128,    // Gifts and matching funds still held
+    pub released_amount: i128,    // Paid to the creator or fallback beneficiary
+    pub refunded_amount: i128,    // Returned to contributors
+    pub clawed_back_amount: i128, // Taken back from escrow by the token issuer
+    pub gift_buckets: Vec<u32>,   // Gifts per order of magnitude, bucket i holds [10^i, 10^(i+1))
+}
+
+/// Dashboard figures of a campaign
+#[derive(Clone)]
+#[contracttype]
+pub struct CampaignStats {
+    pub unique_donors: u32,
+    pub contributions: u32,
+    pub average_gift: i128,
+    pub median_gift_bucket: i128, // Lower bound of the bucket holding the median gift
+    pub gift_buckets: Vec<u32>,
+    pub progress_bps: u32, // Raised over goal, capped at 10000
+    pub escrowed_amount: i128,
+    pub released_amount: i128,
+    pub refunded_amount: i128,
+    pub withdrawable_amount: i128,
+    pub time_left: Option<u64>, // Seconds until the next milestone is due
+}
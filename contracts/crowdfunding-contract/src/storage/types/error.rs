@@ -3,6 +3,7 @@ use soroban_sdk::contracterror;
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
+// Contract specs allow at most 50 error cases; retired codes are never reused
 pub enum Error {
     ContractInitialized = 0,
     ContractNotInitialized = 1,
@@ -27,4 +28,31 @@ pub enum Error {
     MilestoneNotCompleted = 20,
     CannotWithdrawFutureMilestone = 21,
     NoFundsToWithdraw = 22,
+    RoundNotFound = 23,
+    InvalidRoundState = 24,
+    Unauthorized = 25,
+    InvalidCampaignStatus = 27,
+    OrganizationNotFound = 28,
+    InvalidOrganization = 29,
+    InvalidRating = 30,
+    MilestonePlanLocked = 31,
+    AmendmentNotFound = 32,
+    InvalidBudget = 33,
+    InvalidKpi = 34,
+    SettlementNotFound = 35,
+    InvalidVestingSchedule = 36,
+    ReleaseLocked = 37,
+    ReportAlreadyFiled = 39,
+    WithdrawalsPaused = 40,
+    InvalidReportThreshold = 41,
+    ProofRejectedByAuditor = 42,
+    ProofRevoked = 43,
+    IdTooLong = 44,
+    TooManyMilestones = 45,
+    UnsupportedUriScheme = 46,
+    InvalidConfig = 47,
+    TitleTooLong = 48,
+    DescriptionTooLong = 49,
+    UriTooLong = 50,
+    ProofAlreadyExists = 52,
 }
@@ -5,8 +5,42 @@ use soroban_sdk::{contracttype, Address, String};
 pub enum DataKey {
     Admin,
     Token,
-    Campaign(String),              // String-based campaign ID
-    Contribution(String, Address), // (campaign_id, contributor)
-    Proof(String, String),         // (campaign_id, proof_id)
-    Milestone(String, u32),        // (campaign_id, sequence)
+    Campaign(String),                  // String-based campaign ID
+    Contribution(String, Address),     // (campaign_id, contributor)
+    Proof(String, String),             // (campaign_id, proof_id)
+    Milestone(String, u32),            // (campaign_id, sequence)
+    ContributorCount(String),          // campaign_id -> number of contributors
+    Contributor(String, u32),          // (campaign_id, index) -> Address
+    ContributorIndex(String, Address), // (campaign_id, contributor) -> index
+    Round(u32),                        // Matching round ID
+    RoundCount,
+    CampaignRounds(String), // campaign_id -> Vec<u32> of rounds that matched it
+    Moderator(Address),
+    CampaignReviews(String), // campaign_id -> Vec<ReviewEntry>
+    Organization(u32),
+    OrganizationCount,
+    OrganizationCampaigns(u32),    // organization_id -> Vec<String>
+    Reputation(Address),           // creator -> Reputation counters
+    Rating(String, Address),       // (campaign_id, donor)
+    MilestoneAmendment(String),    // Pending plan amendment per campaign
+    Settlement(String),            // campaign_id -> CampaignSettlement
+    CampaignProofs(String),        // campaign_id -> Vec<String> of proof ids
+    FraudFinding(String),          // campaign_id -> FraudFinding
+    CreatorFraudFindings(Address), // creator -> Vec<String> of frozen campaign ids
+    CampaignReports(String),       // campaign_id -> CampaignReports
+    ReportThreshold,               // Share of donations that triggers a review, in bps
+    ReportQueue,                   // Vec<String> of campaigns awaiting report review
+    Auditor(Address),
+    ProofAnnotations(String, String), // (campaign_id, proof_id) -> Vec<ProofAnnotation>
+    AuditorVeto,                      // Whether a reject verdict blocks validation
+    Config,                           // Platform limits on inputs
+    Liabilities,                      // Running total of escrow and round funds owed
+    CampaignTally(String),            // campaign_id -> CampaignTally running totals
+    DonorCampaigns(Address),          // donor -> Vec<String> of supported campaign ids
+    DonorPosition(String, Address),   // (campaign_id, donor) -> DonorPosition
+    PublicContributorCount(String),   // campaign_id -> number of listed contributors
+    PublicContributor(String, u32),   // (campaign_id, index) -> Address
+    PublicContributorIndex(String, Address), // (campaign_id, contributor) -> index
+    Leaderboard(String),              // campaign_id -> Vec<ContributorEntry> of top contributors
+    GivingLines(Address, u32),        // (donor, year) -> Vec<GivingLine>
 }
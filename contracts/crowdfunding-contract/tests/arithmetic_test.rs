@@ -0,0 +1,65 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign_with, setup_contract, CampaignParams};
+use crowdfunding_contract::{storage::types::error::Error, CrowdfundingContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    String,
+) {
+    let (client, token, admin) = setup_contract(env);
+    let params = CampaignParams {
+        goal: i128::MAX,
+        min_donation: 1,
+        ..CampaignParams::default()
+    };
+    let campaign_id = create_campaign_with(env, &client, &admin, "vault", params);
+
+    (client, token, campaign_id)
+}
+
+#[test]
+fn test_total_raised_overflow_is_rejected() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let whale = Address::generate(&env);
+    token.mint(&whale, &i128::MAX);
+    client.contribute(&whale, &campaign_id, &i128::MAX, &false);
+    assert_eq!(client.get_campaign(&campaign_id).total_raised, i128::MAX);
+
+    // One more unit no longer fits in the campaign total
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1);
+    assert_eq!(
+        client.try_contribute(&donor, &campaign_id, &1, &false),
+        Err(Ok(Error::MathOverflow))
+    );
+    assert_eq!(client.get_campaign(&campaign_id).supporters, 1);
+}
+
+#[test]
+fn test_matching_weight_overflow_is_rejected() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let whale = Address::generate(&env);
+    token.mint(&whale, &(i128::MAX / 2));
+    client.contribute(&whale, &campaign_id, &(i128::MAX / 2), &false);
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    client.enroll_campaign(&round_id, &campaign_id);
+
+    // Scaling the contribution for its square root overflows
+    assert_eq!(
+        client.try_finalize_round(&round_id),
+        Err(Ok(Error::MathOverflow))
+    );
+    assert_eq!(client.get_campaign(&campaign_id).matched_amount, 0);
+}
@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{add_milestones, add_proof, create_campaign, setup_contract};
+use crowdfunding_contract::{
+    storage::{structs::audit::AuditVerdict, types::error::Error},
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, String, String) {
+    let (client, token, admin) = setup_contract(env);
+    let campaign_id = create_campaign(env, &client, &admin, "clinic", None);
+    add_milestones(env, &client, &campaign_id, &[1_000]);
+
+    let donor = Address::generate(env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    add_proof(env, &client, &campaign_id, "equipment");
+
+    (client, campaign_id, String::from_str(env, "equipment"))
+}
+
+fn annotate(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    auditor: &Address,
+    campaign_id: &String,
+    proof_id: &String,
+    verdict: AuditVerdict,
+) -> Result<(), Error> {
+    client
+        .try_annotate_proof(
+            auditor,
+            campaign_id,
+            proof_id,
+            &verdict,
+            &String::from_str(env, "ipfs://QmOpinion"),
+            &BytesN::from_array(env, &[3; 32]),
+        )
+        .map(|result| result.unwrap())
+        .map_err(|error| error.unwrap())
+}
+
+#[test]
+fn test_auditors_annotate_proofs() {
+    let env = Env::default();
+    let (client, campaign_id, proof_id) = setup(&env);
+
+    let auditor = Address::generate(&env);
+    assert_eq!(
+        annotate(
+            &env,
+            &client,
+            &auditor,
+            &campaign_id,
+            &proof_id,
+            AuditVerdict::Accept
+        ),
+        Err(Error::Unauthorized)
+    );
+
+    client.add_auditor(&auditor);
+    annotate(
+        &env,
+        &client,
+        &auditor,
+        &campaign_id,
+        &proof_id,
+        AuditVerdict::Concern,
+    )
+    .unwrap();
+    assert_eq!(
+        annotate(
+            &env,
+            &client,
+            &auditor,
+            &campaign_id,
+            &String::from_str(&env, "missing"),
+            AuditVerdict::Accept
+        ),
+        Err(Error::ProofNotFound)
+    );
+
+    // A second opinion from the same auditor replaces the first
+    annotate(
+        &env,
+        &client,
+        &auditor,
+        &campaign_id,
+        &proof_id,
+        AuditVerdict::Accept,
+    )
+    .unwrap();
+    let annotations = client.get_proof_annotations(&campaign_id, &proof_id);
+    assert_eq!(annotations.len(), 1);
+    assert_eq!(annotations.get(0).unwrap().verdict, AuditVerdict::Accept);
+    assert_eq!(
+        annotations.get(0).unwrap().content_hash,
+        BytesN::from_array(&env, &[3; 32])
+    );
+
+    client.remove_auditor(&auditor);
+    assert_eq!(
+        annotate(
+            &env,
+            &client,
+            &auditor,
+            &campaign_id,
+            &proof_id,
+            AuditVerdict::Reject
+        ),
+        Err(Error::Unauthorized)
+    );
+    assert_eq!(
+        client.get_proof_annotations(&campaign_id, &proof_id).len(),
+        1
+    );
+}
+
+#[test]
+fn test_reject_verdict_blocks_validation_with_veto() {
+    let env = Env::default();
+    let (client, campaign_id, proof_id) = setup(&env);
+
+    let auditor = Address::generate(&env);
+    client.add_auditor(&auditor);
+    annotate(
+        &env,
+        &client,
+        &auditor,
+        &campaign_id,
+        &proof_id,
+        AuditVerdict::Reject,
+    )
+    .unwrap();
+
+    client.set_auditor_veto(&true);
+    assert_eq!(
+        client.try_validate_milestone_with_proof(&campaign_id, &1, &proof_id),
+        Err(Ok(Error::ProofRejectedByAuditor))
+    );
+
+    // Without the veto annotations are advisory only
+    client.set_auditor_veto(&false);
+    client.validate_milestone_with_proof(&campaign_id, &1, &proof_id);
+    assert!(client.get_milestone(&campaign_id, &1).completed);
+}
@@ -0,0 +1,182 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign, setup_contract};
+use crowdfunding_contract::{
+    storage::{
+        structs::{
+            budget::{BudgetLineItem, SpendReport},
+            milestone::Vesting,
+        },
+        types::error::Error,
+    },
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, String) {
+    let (client, token, admin) = setup_contract(env);
+    let campaign_id = create_campaign(env, &client, &admin, "well", None);
+
+    client.add_milestone(
+        &campaign_id,
+        &400,
+        &String::from_str(env, "Drilling"),
+        &None,
+        &vec![
+            env,
+            line_item(env, "equipment", 300),
+            line_item(env, "salaries", 100),
+        ],
+        &Vec::new(env),
+        &Vesting::Immediate,
+    );
+    client.add_milestone(
+        &campaign_id,
+        &1_000,
+        &String::from_str(env, "Pump"),
+        &None,
+        &vec![env, line_item(env, "materials", 500)],
+        &Vec::new(env),
+        &Vesting::Immediate,
+    );
+
+    let donor = Address::generate(env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    (client, campaign_id)
+}
+
+fn line_item(env: &Env, category: &str, planned_amount: i128) -> BudgetLineItem {
+    BudgetLineItem {
+        category: String::from_str(env, category),
+        planned_amount,
+        description: String::from_str(env, category),
+    }
+}
+
+#[test]
+fn test_budget_must_fit_milestone_tranche() {
+    let env = Env::default();
+    let (client, _, admin) = setup_contract(&env);
+    let campaign_id = create_campaign(&env, &client, &admin, "well", None);
+    client.add_milestone(
+        &campaign_id,
+        &400,
+        &String::from_str(&env, "Drilling"),
+        &None,
+        &Vec::new(&env),
+        &Vec::new(&env),
+        &Vesting::Immediate,
+    );
+
+    // The second tranche is 600, so 700 of line items cannot be planned
+    assert_eq!(
+        client.try_add_milestone(
+            &campaign_id,
+            &1_000,
+            &String::from_str(&env, "Pump"),
+            &None,
+            &vec![
+                &env,
+                line_item(&env, "materials", 500),
+                line_item(&env, "salaries", 200),
+            ],
+            &Vec::new(&env),
+            &Vesting::Immediate,
+        ),
+        Err(Ok(Error::InvalidBudget))
+    );
+    assert_eq!(
+        client.try_add_milestone(
+            &campaign_id,
+            &1_000,
+            &String::from_str(&env, "Pump"),
+            &None,
+            &vec![&env, line_item(&env, "materials", 0)],
+            &Vec::new(&env),
+            &Vesting::Immediate,
+        ),
+        Err(Ok(Error::InvalidBudget))
+    );
+}
+
+#[test]
+fn test_variance_flags_overruns_above_threshold() {
+    let env = Env::default();
+    let (client, campaign_id) = setup(&env);
+
+    // Equipment 10% over plan, salaries under plan
+    client.add_proof(
+        &String::from_str(&env, "proof-1"),
+        &campaign_id,
+        &String::from_str(&env, "ipfs://QmInvoices"),
+        &String::from_str(&env, "Drilling invoices"),
+        &vec![
+            &env,
+            SpendReport {
+                line_item: 0,
+                actual_amount: 330,
+            },
+            SpendReport {
+                line_item: 1,
+                actual_amount: 80,
+            },
+        ],
+        &Vec::new(&env),
+    );
+    client.validate_milestone_with_proof(&campaign_id, &1, &String::from_str(&env, "proof-1"));
+
+    let report = client.get_budget_variance(&campaign_id, &500);
+    assert_eq!(report.planned_amount, 400);
+    assert_eq!(report.actual_amount, 410);
+    assert_eq!(report.variance, 10);
+    assert!(!report.overrun);
+
+    let drilling = report.milestones.get_unchecked(0);
+    assert!(drilling.reported);
+    assert!(drilling.line_items.get_unchecked(0).overrun);
+    assert_eq!(drilling.line_items.get_unchecked(1).variance, -20);
+    assert!(!drilling.line_items.get_unchecked(1).overrun);
+
+    // Unreported milestones keep their plan but do not count as savings
+    let pump = report.milestones.get_unchecked(1);
+    assert!(!pump.reported);
+    assert_eq!(pump.planned_amount, 500);
+    assert_eq!(pump.actual_amount, 0);
+
+    // With no tolerance the whole campaign is over budget
+    assert!(client.get_budget_variance(&campaign_id, &0).overrun);
+}
+
+#[test]
+fn test_spend_must_match_budget_line_items() {
+    let env = Env::default();
+    let (client, campaign_id) = setup(&env);
+
+    client.add_proof(
+        &String::from_str(&env, "proof-1"),
+        &campaign_id,
+        &String::from_str(&env, "ipfs://QmInvoices"),
+        &String::from_str(&env, "Drilling invoices"),
+        &vec![
+            &env,
+            SpendReport {
+                line_item: 2,
+                actual_amount: 100,
+            },
+        ],
+        &Vec::new(&env),
+    );
+
+    assert_eq!(
+        client.try_validate_milestone_with_proof(
+            &campaign_id,
+            &1,
+            &String::from_str(&env, "proof-1")
+        ),
+        Err(Ok(Error::InvalidBudget))
+    );
+}
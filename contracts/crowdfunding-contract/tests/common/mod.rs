@@ -0,0 +1,164 @@
+//! Fixtures shared by the integration tests
+//!
+//! Each test binary compiles its own copy and uses only some of them.
+#![allow(dead_code)]
+
+use crowdfunding_contract::{
+    storage::structs::milestone::Vesting, CrowdfundingContract, CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String, Vec};
+
+/// Deploy the contract against a fresh stellar asset, with every auth mocked
+///
+/// Returns the client, the token admin client and the platform admin.
+pub fn setup_contract(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    Address,
+) {
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let (client, admin) = register_contract(env, &token_id);
+    let token = token::StellarAssetClient::new(env, &token_id);
+
+    (client, token, admin)
+}
+
+/// Deploy the contract against an existing token, with every auth mocked
+///
+/// Returns the client and the platform admin.
+pub fn register_contract<'a>(
+    env: &Env,
+    token_id: &Address,
+) -> (CrowdfundingContractClient<'a>, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let contract_id = env.register(CrowdfundingContract, (admin.clone(), token_id.clone()));
+
+    (CrowdfundingContractClient::new(env, &contract_id), admin)
+}
+
+/// Campaign fields a test can override; the defaults match `create_campaign`
+pub struct CampaignParams {
+    pub creator: Option<Address>, // Random creator when None
+    pub organization_id: Option<u32>,
+    pub goal: i128,
+    pub min_donation: i128,
+    pub fallback_beneficiary: Option<Address>,
+}
+
+impl Default for CampaignParams {
+    fn default() -> Self {
+        CampaignParams {
+            creator: None,
+            organization_id: None,
+            goal: 1_000,
+            min_donation: 10,
+            fallback_beneficiary: None,
+        }
+    }
+}
+
+/// Add and approve a campaign with a goal of 1000 and a minimum gift of 10
+pub fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    admin: &Address,
+    id: &str,
+    fallback_beneficiary: Option<Address>,
+) -> String {
+    let params = CampaignParams {
+        fallback_beneficiary,
+        ..CampaignParams::default()
+    };
+    create_campaign_with(env, client, admin, id, params)
+}
+
+/// Add and approve a campaign with the given creator, organisation and amounts
+pub fn create_campaign_with(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    admin: &Address,
+    id: &str,
+    params: CampaignParams,
+) -> String {
+    let campaign_id = String::from_str(env, id);
+    client.add_campaign(
+        &campaign_id,
+        &params.creator.unwrap_or_else(|| Address::generate(env)),
+        &params.organization_id,
+        &String::from_str(env, "Water well"),
+        &String::from_str(env, "Village water well"),
+        &params.goal,
+        &params.min_donation,
+        &params.fallback_beneficiary,
+    );
+    client.approve_campaign(admin, &campaign_id);
+
+    campaign_id
+}
+
+/// Add milestones with immediate release at each of `targets`
+pub fn add_milestones(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    campaign_id: &String,
+    targets: &[i128],
+) {
+    for target in targets {
+        client.add_milestone(
+            campaign_id,
+            target,
+            &String::from_str(env, "Stage"),
+            &None,
+            &Vec::new(env),
+            &Vec::new(env),
+            &Vesting::Immediate,
+        );
+    }
+}
+
+/// Deploy the contract with an approved campaign whose milestones need 400 and 1000
+pub fn setup(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    String,
+) {
+    let (client, token, admin) = setup_contract(env);
+    let campaign_id = create_campaign(env, &client, &admin, "well", None);
+    add_milestones(env, &client, &campaign_id, &[400, 1_000]);
+
+    (client, token, campaign_id)
+}
+
+/// Submit a proof without spend or KPI reports
+pub fn add_proof(env: &Env, client: &CrowdfundingContractClient, campaign_id: &String, id: &str) {
+    client.add_proof(
+        &String::from_str(env, id),
+        campaign_id,
+        &String::from_str(env, "ipfs://QmWell"),
+        &String::from_str(env, "Site report"),
+        &Vec::new(env),
+        &Vec::new(env),
+    );
+}
+
+/// Validate milestone `sequence` with a new proof named `proof-<sequence>`
+pub fn validate(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    campaign_id: &String,
+    sequence: u32,
+) {
+    let proof_id = format!("proof-{sequence}");
+    add_proof(env, client, campaign_id, &proof_id);
+    client.validate_milestone_with_proof(campaign_id, &sequence, &String::from_str(env, &proof_id));
+}
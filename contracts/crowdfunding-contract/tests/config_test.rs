@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup_contract;
+use crowdfunding_contract::{
+    storage::{structs::milestone::Vesting, types::error::Error},
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+fn setup(env: &Env) -> CrowdfundingContractClient<'_> {
+    let (client, _, _) = setup_contract(env);
+    client
+}
+
+fn add_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    campaign_id: &str,
+    title: &str,
+    goal: i128,
+) -> Result<(), Error> {
+    client
+        .try_add_campaign(
+            &String::from_str(env, campaign_id),
+            &Address::generate(env),
+            &None,
+            &String::from_str(env, title),
+            &String::from_str(env, "Description"),
+            &goal,
+            &1,
+            &None,
+        )
+        .map(|result| result.unwrap())
+        .map_err(|error| error.unwrap())
+}
+
+fn add_proof(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    id: &str,
+    uri: &str,
+) -> Result<(), Error> {
+    client
+        .try_add_proof(
+            &String::from_str(env, id),
+            &String::from_str(env, "garden"),
+            &String::from_str(env, uri),
+            &String::from_str(env, "Photos"),
+            &Vec::new(env),
+            &Vec::new(env),
+        )
+        .map(|result| result.unwrap())
+        .map_err(|error| error.unwrap())
+}
+
+#[test]
+fn test_campaign_inputs_respect_limits() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    client.set_text_limits(&16, &8, &200, &128);
+    assert_eq!(
+        add_campaign(&env, &client, "garden", "Community garden", 1_000),
+        Err(Error::TitleTooLong)
+    );
+    assert_eq!(
+        add_campaign(&env, &client, "a-very-long-campaign-id", "Garden", 1_000),
+        Err(Error::IdTooLong)
+    );
+
+    assert_eq!(
+        client.try_set_goal_bounds(&500, &100),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.set_goal_bounds(&100, &10_000);
+    assert_eq!(
+        add_campaign(&env, &client, "garden", "Garden", 50_000),
+        Err(Error::InvalidGoalAmount)
+    );
+    assert_eq!(
+        add_campaign(&env, &client, "garden", "Garden", 0),
+        Err(Error::InvalidGoalAmount)
+    );
+    add_campaign(&env, &client, "garden", "Garden", 1_000).unwrap();
+
+    let config = client.get_config();
+    assert_eq!(config.max_title_length, 8);
+    assert_eq!(config.max_goal, 10_000);
+
+    client.set_max_milestones(&2);
+    let campaign_id = String::from_str(&env, "garden");
+    for target in [300, 600] {
+        client.add_milestone(
+            &campaign_id,
+            &target,
+            &String::from_str(&env, "Stage"),
+            &None,
+            &Vec::new(&env),
+            &Vec::new(&env),
+            &Vesting::Immediate,
+        );
+    }
+    assert_eq!(
+        client.try_add_milestone(
+            &campaign_id,
+            &900,
+            &String::from_str(&env, "Stage"),
+            &None,
+            &Vec::new(&env),
+            &Vec::new(&env),
+            &Vesting::Immediate,
+        ),
+        Err(Ok(Error::TooManyMilestones))
+    );
+}
+
+#[test]
+fn test_proof_uri_schemes_are_allow_listed() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(
+        add_proof(&env, &client, "photos", "https://example.com/photos"),
+        Err(Error::UnsupportedUriScheme)
+    );
+    assert_eq!(
+        add_proof(&env, &client, "photos", "ipfs:/QmBroken"),
+        Err(Error::UnsupportedUriScheme)
+    );
+    add_proof(&env, &client, "photos", "ar://photos").unwrap();
+
+    assert_eq!(
+        client.try_set_allowed_uri_schemes(&Vec::new(&env)),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.set_allowed_uri_schemes(&vec![&env, String::from_str(&env, "https://")]);
+    add_proof(&env, &client, "receipts", "https://example.com/photos").unwrap();
+
+    client.set_text_limits(&64, &100, &1_000, &16);
+    assert_eq!(
+        add_proof(&env, &client, "invoices", "https://example.com/photos"),
+        Err(Error::UriTooLong)
+    );
+}
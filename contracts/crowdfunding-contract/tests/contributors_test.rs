@@ -0,0 +1,140 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign_with, setup_contract, CampaignParams};
+use crowdfunding_contract::CrowdfundingContractClient;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String, Vec};
+
+fn setup(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    String,
+) {
+    let (client, token, admin) = setup_contract(env);
+    let params = CampaignParams {
+        goal: 100_000,
+        min_donation: 1,
+        ..CampaignParams::default()
+    };
+    let campaign_id = create_campaign_with(env, &client, &admin, "choir", params);
+
+    (client, token, campaign_id)
+}
+
+fn give(
+    client: &CrowdfundingContractClient,
+    token: &token::StellarAssetClient,
+    campaign_id: &String,
+    donor: &Address,
+    amount: i128,
+    anonymous: bool,
+) {
+    token.mint(donor, &amount);
+    client.contribute(donor, campaign_id, &amount, &anonymous);
+}
+
+#[test]
+fn test_contributor_list_hides_anonymous_donors() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let (ana, ben, cleo) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    give(&client, &token, &campaign_id, &ana, 100, false);
+    give(&client, &token, &campaign_id, &ben, 200, true);
+    give(&client, &token, &campaign_id, &cleo, 300, false);
+    give(&client, &token, &campaign_id, &ana, 50, false);
+
+    let contributors = client.list_contributors(&campaign_id, &0, &10);
+    assert_eq!(contributors.len(), 2);
+    assert_eq!(contributors.get_unchecked(0).contributor, ana);
+    assert_eq!(contributors.get_unchecked(0).amount, 150);
+    assert_eq!(contributors.get_unchecked(1).contributor, cleo);
+
+    let page = client.list_contributors(&campaign_id, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).contributor, cleo);
+
+    // Opting out later hides earlier gifts as well
+    give(&client, &token, &campaign_id, &cleo, 10, true);
+    give(&client, &token, &campaign_id, &cleo, 10, false);
+    assert_eq!(client.list_contributors(&campaign_id, &0, &10).len(), 1);
+    assert_eq!(client.get_leaderboard(&campaign_id).len(), 1);
+
+    client.cancel_campaign(&campaign_id);
+    client.refund(&ana, &campaign_id);
+    assert_eq!(client.list_contributors(&campaign_id, &0, &10).len(), 0);
+    assert_eq!(client.get_leaderboard(&campaign_id).len(), 0);
+}
+
+#[test]
+fn test_leaderboard_keeps_top_contributors_sorted() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let mut donors = Vec::new(&env);
+    for amount in 1..=12 {
+        let donor = Address::generate(&env);
+        give(&client, &token, &campaign_id, &donor, amount * 10, false);
+        donors.push_back(donor);
+    }
+
+    let leaderboard = client.get_leaderboard(&campaign_id);
+    assert_eq!(leaderboard.len(), 10);
+    assert_eq!(leaderboard.get_unchecked(0).amount, 120);
+    assert_eq!(leaderboard.get_unchecked(9).amount, 30);
+
+    // A repeat gift moves the smallest donor to the top
+    let first = donors.get_unchecked(0);
+    give(&client, &token, &campaign_id, &first, 200, false);
+
+    let leaderboard = client.get_leaderboard(&campaign_id);
+    assert_eq!(leaderboard.len(), 10);
+    assert_eq!(leaderboard.get_unchecked(0).contributor, first);
+    assert_eq!(leaderboard.get_unchecked(0).amount, 210);
+    assert_eq!(leaderboard.get_unchecked(9).amount, 40);
+
+    assert_eq!(client.list_contributors(&campaign_id, &0, &100).len(), 12);
+}
+
+#[test]
+fn test_leaderboard_refills_after_refund() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let mut donors = Vec::new(&env);
+    for amount in 1..=12 {
+        let donor = Address::generate(&env);
+        give(&client, &token, &campaign_id, &donor, amount * 10, false);
+        donors.push_back(donor);
+    }
+
+    client.cancel_campaign(&campaign_id);
+    client.refund(&donors.get_unchecked(11), &campaign_id);
+
+    // The next largest listed contributor takes the free place
+    let leaderboard = client.get_leaderboard(&campaign_id);
+    assert_eq!(leaderboard.len(), 10);
+    assert_eq!(leaderboard.get_unchecked(0).amount, 110);
+    assert_eq!(
+        leaderboard.get_unchecked(9).contributor,
+        donors.get_unchecked(1)
+    );
+    assert_eq!(leaderboard.get_unchecked(9).amount, 20);
+
+    // The last listed contributor moves into the refunded one's place
+    client.refund(&donors.get_unchecked(0), &campaign_id);
+    let contributors = client.list_contributors(&campaign_id, &0, &100);
+    assert_eq!(contributors.len(), 10);
+    assert_eq!(
+        contributors.get_unchecked(0).contributor,
+        donors.get_unchecked(10)
+    );
+    assert_eq!(client.get_leaderboard(&campaign_id).len(), 10);
+}
@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign, setup_contract};
+use crowdfunding_contract::storage::types::error::Error;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+#[test]
+fn test_sweep_moves_only_stray_tokens() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+    let campaign_id = create_campaign(&env, &client, &admin, "shelter", None);
+
+    let donors = [Address::generate(&env), Address::generate(&env)];
+    for donor in donors.iter() {
+        token.mint(donor, &300);
+        client.contribute(donor, &campaign_id, &300, &false);
+    }
+    client.refund(&donors[0], &campaign_id);
+
+    let reconciliation = client.reconcile();
+    assert_eq!(reconciliation.balance, 300);
+    assert_eq!(reconciliation.liabilities, 300);
+    assert_eq!(reconciliation.surplus, 0);
+    assert_eq!(
+        client.try_sweep_surplus(&Address::generate(&env)),
+        Err(Ok(Error::NoFundsToWithdraw))
+    );
+
+    // Tokens sent straight to the contract are not owed to anyone
+    token.mint(&client.address, &75);
+    assert_eq!(client.reconcile().surplus, 75);
+
+    let treasury = Address::generate(&env);
+    assert_eq!(client.sweep_surplus(&treasury), 75);
+    assert_eq!(
+        token::Client::new(&env, &token.address).balance(&treasury),
+        75
+    );
+
+    let reconciliation = client.reconcile();
+    assert_eq!(reconciliation.balance, 300);
+    assert_eq!(reconciliation.surplus, 0);
+
+    // The remaining escrow still covers the refund
+    client.refund(&donors[1], &campaign_id);
+    assert_eq!(client.reconcile().liabilities, 0);
+}
+
+#[test]
+fn test_round_dust_becomes_surplus() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    for id in ["shelter", "kitchen", "clinic"] {
+        let campaign_id = create_campaign(&env, &client, &admin, id, None);
+        for _ in 0..2 {
+            let donor = Address::generate(&env);
+            token.mint(&donor, &100);
+            client.contribute(&donor, &campaign_id, &100, &false);
+        }
+        client.enroll_campaign(&round_id, &campaign_id);
+    }
+
+    let funder = Address::generate(&env);
+    token.mint(&funder, &1_000);
+    client.fund_round(&funder, &round_id, &1_000);
+    assert_eq!(client.reconcile().liabilities, 1_600);
+
+    // Three equal weights split 1000 into 333 each, leaving 1 unallocated
+    client.finalize_round(&round_id);
+    assert_eq!(client.get_round(&round_id).distributed, 999);
+
+    let reconciliation = client.reconcile();
+    assert_eq!(reconciliation.balance, 1_600);
+    assert_eq!(reconciliation.liabilities, 1_599);
+    assert_eq!(reconciliation.surplus, 1);
+}
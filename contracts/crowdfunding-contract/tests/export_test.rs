@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign_with, setup_contract, CampaignParams};
+use crowdfunding_contract::{
+    storage::{structs::milestone::Vesting, types::error::Error},
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String, Vec};
+
+fn setup(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    String,
+) {
+    let (client, token, admin) = setup_contract(env);
+    let params = CampaignParams {
+        goal: 900,
+        ..CampaignParams::default()
+    };
+    let campaign_id = create_campaign_with(env, &client, &admin, "library", params);
+
+    for (target, description) in [(300, "Shelves"), (600, "Books"), (900, "Reading room")] {
+        client.add_milestone(
+            &campaign_id,
+            &target,
+            &String::from_str(env, description),
+            &None,
+            &Vec::new(env),
+            &Vec::new(env),
+            &Vesting::Immediate,
+        );
+    }
+
+    (client, token, campaign_id)
+}
+
+#[test]
+fn test_export_pages_through_milestones_and_proofs() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &900);
+    client.contribute(&donor, &campaign_id, &900, &false);
+
+    let proof_ids = [
+        "p-01", "p-02", "p-03", "p-04", "p-05", "p-06", "p-07", "p-08", "p-09",
+    ];
+    for proof_id in proof_ids {
+        client.add_proof(
+            &String::from_str(&env, proof_id),
+            &campaign_id,
+            &String::from_str(&env, "ipfs://QmLibrary"),
+            &String::from_str(&env, "Receipts"),
+            &Vec::new(&env),
+            &Vec::new(&env),
+        );
+    }
+    client.validate_milestone_with_proof(&campaign_id, &1, &String::from_str(&env, "p-01"));
+    client.withdraw_milestone_funds(&campaign_id, &1);
+
+    let first = client.export_campaign(&campaign_id, &0);
+    assert_eq!(first.format_version, 1);
+    assert_eq!(first.campaign.id, campaign_id);
+    assert_eq!(first.milestones.len(), 3);
+    assert_eq!(first.proofs.len(), 7);
+    assert_eq!(first.next_cursor, Some(10));
+    assert_eq!(first.contributions.supporters, 1);
+    assert_eq!(first.contributions.contributed_amount, 900);
+    assert_eq!(first.contributions.released_amount, 300);
+    assert_eq!(first.contributions.withdrawable_amount, 0);
+
+    let second = client.export_campaign(&campaign_id, &10);
+    assert_eq!(second.milestones.len(), 0);
+    assert_eq!(second.proofs.len(), 2);
+    assert_eq!(
+        second.proofs.get_unchecked(1).id,
+        String::from_str(&env, "p-09")
+    );
+    assert_eq!(second.next_cursor, None);
+    assert_eq!(second.contributions.released_amount, 300);
+}
+
+#[test]
+fn test_export_fits_small_campaign_in_one_page() {
+    let env = Env::default();
+    let (client, _, campaign_id) = setup(&env);
+
+    let export = client.export_campaign(&campaign_id, &0);
+    assert_eq!(export.milestones.len(), 3);
+    assert_eq!(export.proofs.len(), 0);
+    assert_eq!(export.next_cursor, None);
+
+    assert_eq!(
+        client
+            .try_export_campaign(&String::from_str(&env, "missing"), &0)
+            .err(),
+        Some(Ok(Error::CampaignNotFound))
+    );
+}
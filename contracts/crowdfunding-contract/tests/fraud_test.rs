@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{setup, validate};
+use crowdfunding_contract::storage::{structs::campaign::CampaignStatus, types::error::Error};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String};
+
+#[test]
+fn test_frozen_escrow_is_clawed_back_pro_rata() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let balance = token::Client::new(&env, &token.address);
+
+    let donors = [Address::generate(&env), Address::generate(&env)];
+    for (donor, amount) in donors.iter().zip([600, 400]) {
+        token.mint(donor, &amount);
+        client.contribute(donor, &campaign_id, &amount, &false);
+    }
+
+    validate(&env, &client, &campaign_id, 1);
+    assert_eq!(client.withdraw_milestone_funds(&campaign_id, &1), 400);
+    validate(&env, &client, &campaign_id, 2);
+
+    // The validated but unwithdrawn tranche is recovered too
+    let evidence_hash = BytesN::from_array(&env, &[7; 32]);
+    let finding = client.freeze_campaign(
+        &campaign_id,
+        &String::from_str(&env, "ipfs://QmAudit"),
+        &evidence_hash,
+    );
+    assert_eq!(finding.recoverable_amount, 600);
+    assert_eq!(finding.evidence_hash, evidence_hash);
+
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.status, CampaignStatus::Frozen);
+    assert_eq!(campaign.withdrawable_amount, 0);
+    assert_eq!(
+        client.try_withdraw_milestone_funds(&campaign_id, &2),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+    assert_eq!(
+        client.try_refund(&donors[0], &campaign_id),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    assert_eq!(client.claim_clawback(&donors[0], &campaign_id), 360);
+    assert_eq!(client.claim_clawback(&donors[1], &campaign_id), 240);
+    assert_eq!(balance.balance(&donors[0]), 360);
+    assert_eq!(balance.balance(&donors[1]), 240);
+    assert_eq!(
+        client.try_claim_clawback(&donors[0], &campaign_id),
+        Err(Ok(Error::ContributionNotFound))
+    );
+    assert_eq!(client.get_fraud_finding(&campaign_id).refunded_amount, 600);
+
+    // The finding is public and weighs on the creator's reputation
+    assert_eq!(
+        client.get_creator_fraud_findings(&campaign.creator),
+        vec![&env, campaign_id.clone()]
+    );
+    assert_eq!(client.get_reputation(&campaign.creator).fraud_findings, 1);
+}
+
+#[test]
+fn test_clawback_requires_frozen_campaign() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &500);
+    client.contribute(&donor, &campaign_id, &500, &false);
+
+    assert_eq!(
+        client.try_claim_clawback(&donor, &campaign_id),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+    assert_eq!(
+        client.try_get_fraud_finding(&campaign_id).err(),
+        Some(Ok(Error::InvalidCampaignStatus))
+    );
+
+    let evidence_uri = String::from_str(&env, "ar://audit");
+    let evidence_hash = BytesN::from_array(&env, &[1; 32]);
+    client.freeze_campaign(&campaign_id, &evidence_uri, &evidence_hash);
+    assert_eq!(
+        client
+            .try_freeze_campaign(&campaign_id, &evidence_uri, &evidence_hash)
+            .err(),
+        Some(Ok(Error::InvalidCampaignStatus))
+    );
+    assert_eq!(
+        client.try_contribute(&donor, &campaign_id, &100, &false),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+}
+
+#[test]
+fn test_freeze_after_partial_refund_recovers_escrow_left() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let balance = token::Client::new(&env, &token.address);
+
+    let donors = [Address::generate(&env), Address::generate(&env)];
+    for (donor, amount) in donors.iter().zip([600, 400]) {
+        token.mint(donor, &amount);
+        client.contribute(donor, &campaign_id, &amount, &false);
+    }
+    validate(&env, &client, &campaign_id, 1);
+    client.withdraw_milestone_funds(&campaign_id, &1);
+
+    // The first refund gets 60% of 600 back, leaving 240 in escrow
+    client.refund(&donors[0], &campaign_id);
+    assert_eq!(balance.balance(&donors[0]), 360);
+
+    let finding = client.freeze_campaign(
+        &campaign_id,
+        &String::from_str(&env, "ipfs://QmAudit"),
+        &BytesN::from_array(&env, &[7; 32]),
+    );
+    assert_eq!(finding.recoverable_amount, 240);
+    assert_eq!(finding.contributed_amount, 400);
+
+    assert_eq!(client.claim_clawback(&donors[1], &campaign_id), 240);
+    assert_eq!(balance.balance(&client.address), 0);
+}
@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign, setup_contract};
+use crowdfunding_contract::{
+    storage::{
+        structs::{
+            kpi::{Kpi, KpiReport},
+            milestone::Vesting,
+        },
+        types::error::Error,
+    },
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, String) {
+    let (client, token, admin) = setup_contract(env);
+    let campaign_id = create_campaign(env, &client, &admin, "kitchen", None);
+
+    client.add_milestone(
+        &campaign_id,
+        &500,
+        &String::from_str(env, "First month"),
+        &None,
+        &Vec::new(env),
+        &vec![
+            env,
+            kpi(env, "meals served", "meals", 1_000),
+            kpi(env, "volunteers trained", "people", 10),
+        ],
+        &Vesting::Immediate,
+    );
+    client.add_milestone(
+        &campaign_id,
+        &1_000,
+        &String::from_str(env, "Second month"),
+        &None,
+        &Vec::new(env),
+        &vec![env, kpi(env, "meals served", "meals", 1_000)],
+        &Vesting::Immediate,
+    );
+
+    let donor = Address::generate(env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    (client, campaign_id)
+}
+
+fn kpi(env: &Env, name: &str, unit: &str, target: i128) -> Kpi {
+    Kpi {
+        name: String::from_str(env, name),
+        unit: String::from_str(env, unit),
+        target,
+    }
+}
+
+fn add_proof(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    campaign_id: &String,
+    id: &str,
+    kpi_values: Vec<KpiReport>,
+) {
+    client.add_proof(
+        &String::from_str(env, id),
+        campaign_id,
+        &String::from_str(env, "ipfs://QmKitchen"),
+        &String::from_str(env, "Meal logs"),
+        &Vec::new(env),
+        &kpi_values,
+    );
+}
+
+#[test]
+fn test_impact_aggregates_attested_kpis() {
+    let env = Env::default();
+    let (client, campaign_id) = setup(&env);
+
+    add_proof(
+        &env,
+        &client,
+        &campaign_id,
+        "proof-1",
+        vec![
+            &env,
+            KpiReport {
+                kpi: 0,
+                value: 1_200,
+            },
+        ],
+    );
+    client.validate_milestone_with_proof(&campaign_id, &1, &String::from_str(&env, "proof-1"));
+
+    // KPIs missing from the proof are attested as zero
+    let milestone = client.get_milestone(&campaign_id, &1);
+    assert_eq!(milestone.kpi_values, vec![&env, 1_200, 0]);
+
+    let impact = client.get_campaign_impact(&campaign_id);
+    assert_eq!(impact.milestones_completed, 1);
+    assert_eq!(impact.kpis.len(), 2);
+
+    let meals = impact.kpis.get_unchecked(0);
+    assert_eq!(meals.target, 2_000);
+    assert_eq!(meals.achieved, 1_200);
+    assert_eq!(meals.progress_bps, 6_000);
+
+    let volunteers = impact.kpis.get_unchecked(1);
+    assert_eq!(volunteers.target, 10);
+    assert_eq!(volunteers.achieved, 0);
+    assert_eq!(volunteers.progress_bps, 0);
+}
+
+#[test]
+fn test_invalid_kpis_are_rejected() {
+    let env = Env::default();
+    let (client, campaign_id) = setup(&env);
+
+    add_proof(
+        &env,
+        &client,
+        &campaign_id,
+        "proof-1",
+        vec![&env, KpiReport { kpi: 2, value: 5 }],
+    );
+    assert_eq!(
+        client.try_validate_milestone_with_proof(
+            &campaign_id,
+            &1,
+            &String::from_str(&env, "proof-1")
+        ),
+        Err(Ok(Error::InvalidKpi))
+    );
+
+    add_proof(
+        &env,
+        &client,
+        &campaign_id,
+        "proof-2",
+        vec![&env, KpiReport { kpi: 0, value: -1 }],
+    );
+    assert_eq!(
+        client.try_validate_milestone_with_proof(
+            &campaign_id,
+            &1,
+            &String::from_str(&env, "proof-2")
+        ),
+        Err(Ok(Error::InvalidKpi))
+    );
+}
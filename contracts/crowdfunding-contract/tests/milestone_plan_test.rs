@@ -0,0 +1,250 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign, setup_contract};
+use crowdfunding_contract::{
+    storage::{
+        structs::milestone::{MilestoneDraft, Vesting},
+        types::error::Error,
+    },
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
+
+fn setup(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    String,
+) {
+    let (client, token, admin) = setup_contract(env);
+    let campaign_id = create_campaign(env, &client, &admin, "library", None);
+
+    for (target, description) in [(300, "Books"), (800, "Shelves"), (1_000, "Opening")] {
+        client.add_milestone(
+            &campaign_id,
+            &target,
+            &String::from_str(env, description),
+            &None,
+            &Vec::new(env),
+            &Vec::new(env),
+            &Vesting::Immediate,
+        );
+    }
+
+    (client, token, campaign_id)
+}
+
+fn contribute(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token: &token::StellarAssetClient,
+    campaign_id: &String,
+    amount: i128,
+) -> Address {
+    let donor = Address::generate(env);
+    token.mint(&donor, &amount);
+    client.contribute(&donor, campaign_id, &amount, &false);
+    donor
+}
+
+#[test]
+fn test_plan_is_editable_before_contributions() {
+    let env = Env::default();
+    let (client, _, campaign_id) = setup(&env);
+
+    // Tranches of 300, 500 and 200 are moved with their milestones
+    client.reorder_milestones(&campaign_id, &vec![&env, 2, 1, 3]);
+    let milestones = client.get_campaign_milestones(&campaign_id);
+    assert_eq!(
+        milestones.get_unchecked(0).description,
+        String::from_str(&env, "Shelves")
+    );
+    assert_eq!(milestones.get_unchecked(0).target_amount, 500);
+    assert_eq!(milestones.get_unchecked(1).target_amount, 800);
+    assert_eq!(milestones.get_unchecked(2).target_amount, 1_000);
+
+    client.update_milestone(
+        &campaign_id,
+        &3,
+        &900,
+        &String::from_str(&env, "Opening day"),
+        &None,
+        &Vec::new(&env),
+        &Vec::new(&env),
+        &Vesting::Immediate,
+    );
+    client.remove_milestone(&campaign_id, &1);
+
+    let milestones = client.get_campaign_milestones(&campaign_id);
+    assert_eq!(milestones.len(), 2);
+    assert_eq!(client.get_campaign(&campaign_id).milestones_count, 2);
+    assert_eq!(milestones.get_unchecked(0).sequence, 1);
+    assert_eq!(milestones.get_unchecked(0).target_amount, 800);
+    assert_eq!(milestones.get_unchecked(1).target_amount, 900);
+    assert_eq!(
+        client.try_get_milestone(&campaign_id, &3).err(),
+        Some(Ok(Error::MilestoneNotFound))
+    );
+
+    assert_eq!(
+        client.try_update_milestone(
+            &campaign_id,
+            &2,
+            &700,
+            &String::from_str(&env, "Opening day"),
+            &None,
+            &Vec::new(&env),
+            &Vec::new(&env),
+            &Vesting::Immediate,
+        ),
+        Err(Ok(Error::MilestoneAmountNotIncreasing))
+    );
+}
+
+#[test]
+fn test_plan_locks_after_first_contribution() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    let majority = contribute(&env, &client, &token, &campaign_id, 600);
+    let minority = contribute(&env, &client, &token, &campaign_id, 400);
+
+    assert_eq!(
+        client.try_add_milestone(
+            &campaign_id,
+            &1_000,
+            &String::from_str(&env, "Extra"),
+            &None,
+            &Vec::new(&env),
+            &Vec::new(&env),
+            &Vesting::Immediate,
+        ),
+        Err(Ok(Error::MilestonePlanLocked))
+    );
+    assert_eq!(
+        client.try_remove_milestone(&campaign_id, &3),
+        Err(Ok(Error::MilestonePlanLocked))
+    );
+
+    let plan = vec![
+        &env,
+        MilestoneDraft {
+            target_amount: 600,
+            description: String::from_str(&env, "Books and shelves"),
+            due_date: None,
+            budget: Vec::new(&env),
+            kpis: Vec::new(&env),
+            vesting: Vesting::Immediate,
+        },
+        MilestoneDraft {
+            target_amount: 1_000,
+            description: String::from_str(&env, "Opening"),
+            due_date: None,
+            budget: Vec::new(&env),
+            kpis: Vec::new(&env),
+            vesting: Vesting::Immediate,
+        },
+    ];
+    client.propose_milestone_amendment(
+        &campaign_id,
+        &plan,
+        &String::from_str(&env, "Supplier sells books and shelves together"),
+    );
+
+    // 400 of 1000 is not a majority yet
+    client.vote_milestone_amendment(&minority, &campaign_id);
+    assert_eq!(client.get_campaign(&campaign_id).milestones_count, 3);
+    assert_eq!(
+        client.get_milestone_amendment(&campaign_id).approved_weight,
+        400
+    );
+
+    client.vote_milestone_amendment(&majority, &campaign_id);
+    let milestones = client.get_campaign_milestones(&campaign_id);
+    assert_eq!(milestones.len(), 2);
+    assert_eq!(milestones.get_unchecked(0).target_amount, 600);
+    assert_eq!(
+        client.try_get_milestone_amendment(&campaign_id).err(),
+        Some(Ok(Error::AmendmentNotFound))
+    );
+}
+
+#[test]
+fn test_admin_can_approve_amendment() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    contribute(&env, &client, &token, &campaign_id, 1_000);
+
+    let plan = vec![
+        &env,
+        MilestoneDraft {
+            target_amount: 1_000,
+            description: String::from_str(&env, "Everything"),
+            due_date: None,
+            budget: Vec::new(&env),
+            kpis: Vec::new(&env),
+            vesting: Vesting::Immediate,
+        },
+    ];
+    client.propose_milestone_amendment(
+        &campaign_id,
+        &plan,
+        &String::from_str(&env, "Single delivery"),
+    );
+    client.approve_milestone_amendment(&campaign_id);
+
+    assert_eq!(client.get_campaign(&campaign_id).milestones_count, 1);
+    assert_eq!(
+        client.get_milestone(&campaign_id, &1).description,
+        String::from_str(&env, "Everything")
+    );
+}
+
+#[test]
+fn test_refunded_approvals_stop_counting() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    contribute(&env, &client, &token, &campaign_id, 500);
+
+    assert_eq!(
+        client.try_propose_milestone_amendment(
+            &campaign_id,
+            &Vec::new(&env),
+            &String::from_str(&env, "Drop everything"),
+        ),
+        Err(Ok(Error::MilestoneNotFound))
+    );
+
+    let plan = vec![
+        &env,
+        MilestoneDraft {
+            target_amount: 1_000,
+            description: String::from_str(&env, "Everything"),
+            due_date: None,
+            budget: Vec::new(&env),
+            kpis: Vec::new(&env),
+            vesting: Vesting::Immediate,
+        },
+    ];
+    client.propose_milestone_amendment(
+        &campaign_id,
+        &plan,
+        &String::from_str(&env, "Single delivery"),
+    );
+
+    // The same 400 voted, refunded and given again from a new address
+    for _ in 0..3 {
+        let donor = contribute(&env, &client, &token, &campaign_id, 400);
+        client.vote_milestone_amendment(&donor, &campaign_id);
+        client.refund(&donor, &campaign_id);
+    }
+
+    // Only the donor still holding the 400 counted at the last vote
+    assert_eq!(
+        client.get_milestone_amendment(&campaign_id).approved_weight,
+        400
+    );
+    assert_eq!(client.get_campaign(&campaign_id).milestones_count, 3);
+}
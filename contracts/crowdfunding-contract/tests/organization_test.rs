@@ -0,0 +1,112 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign_with, setup_contract, CampaignParams};
+use crowdfunding_contract::{
+    storage::{
+        structs::{milestone::Vesting, organization::VerificationLevel},
+        types::error::Error,
+    },
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String, Vec};
+
+fn register_organization(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    controller: &Address,
+) -> u32 {
+    client.register_organization(
+        controller,
+        &String::from_str(env, "Water For All"),
+        &BytesN::from_array(env, &[7u8; 32]),
+        &String::from_str(env, "https://waterforall.org"),
+    )
+}
+
+#[test]
+fn test_organization_tracks_campaigns_and_totals() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let founder = Address::generate(&env);
+    let treasurer = Address::generate(&env);
+    let organization_id = register_organization(&env, &client, &founder);
+    client.add_organization_controller(&founder, &organization_id, &treasurer);
+    client.verify_organization(&admin, &organization_id, &VerificationLevel::Verified);
+
+    // Any controller can open campaigns for the organisation
+    let params = CampaignParams {
+        creator: Some(treasurer.clone()),
+        organization_id: Some(organization_id),
+        ..CampaignParams::default()
+    };
+    let campaign_id = create_campaign_with(&env, &client, &admin, "wells-2025", params);
+    client.add_milestone(
+        &campaign_id,
+        &500,
+        &String::from_str(&env, "First well"),
+        &None,
+        &Vec::new(&env),
+        &Vec::new(&env),
+        &Vesting::Immediate,
+    );
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &800);
+    client.contribute(&donor, &campaign_id, &800, &false);
+
+    let proof_id = String::from_str(&env, "proof-1");
+    client.add_proof(
+        &proof_id,
+        &campaign_id,
+        &String::from_str(&env, "ipfs://QmWell"),
+        &String::from_str(&env, "Well drilled"),
+        &Vec::new(&env),
+        &Vec::new(&env),
+    );
+    client.validate_milestone_with_proof(&campaign_id, &1, &proof_id);
+    client.withdraw_milestone_funds(&campaign_id, &1);
+
+    let organization = client.get_organization(&organization_id);
+    assert_eq!(organization.controllers, vec![&env, founder, treasurer]);
+    assert_eq!(organization.verification, VerificationLevel::Verified);
+    assert_eq!(organization.total_raised, 800);
+    assert_eq!(organization.total_released, 500);
+    assert_eq!(
+        client.get_organization_campaigns(&organization_id),
+        vec![&env, campaign_id.clone()]
+    );
+    assert_eq!(
+        client.get_campaign(&campaign_id).organization_id,
+        Some(organization_id)
+    );
+}
+
+#[test]
+fn test_only_controllers_can_link_campaigns() {
+    let env = Env::default();
+    let (client, _, _) = setup_contract(&env);
+
+    let founder = Address::generate(&env);
+    let organization_id = register_organization(&env, &client, &founder);
+
+    let result = client.try_add_campaign(
+        &String::from_str(&env, "impostor"),
+        &Address::generate(&env),
+        &Some(organization_id),
+        &String::from_str(&env, "Wells"),
+        &String::from_str(&env, "Three wells"),
+        &1_000,
+        &10,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    // The last controller cannot be removed
+    assert_eq!(
+        client.try_remove_organization_controller(&founder, &organization_id, &founder),
+        Err(Ok(Error::InvalidOrganization))
+    );
+}
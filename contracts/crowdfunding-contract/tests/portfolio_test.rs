@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign, setup_contract};
+use crowdfunding_contract::storage::structs::campaign::CampaignStatus;
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+#[test]
+fn test_portfolio_lists_supported_campaigns() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let refunded = create_campaign(&env, &client, &admin, "refunded", None);
+    let active = create_campaign(&env, &client, &admin, "active", None);
+    let frozen = create_campaign(&env, &client, &admin, "frozen", None);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &700);
+    client.contribute(&donor, &refunded, &100, &false);
+    client.contribute(&donor, &active, &150, &false);
+    client.contribute(&donor, &frozen, &300, &false);
+    client.contribute(&donor, &active, &50, &false);
+    client.refund(&donor, &refunded);
+
+    // Another donor's share of the frozen escrow
+    let other = Address::generate(&env);
+    token.mint(&other, &300);
+    client.contribute(&other, &frozen, &300, &false);
+    client.freeze_campaign(
+        &frozen,
+        &String::from_str(&env, "ipfs://QmEvidence"),
+        &BytesN::from_array(&env, &[7; 32]),
+    );
+
+    let portfolio = client.get_donor_portfolio(&donor, &0, &10);
+    assert_eq!(portfolio.len(), 3);
+
+    let entry = portfolio.get_unchecked(0);
+    assert_eq!(entry.campaign_id, refunded);
+    assert_eq!(entry.status, CampaignStatus::Approved);
+    assert_eq!(entry.contributed, 100);
+    assert_eq!(entry.refunded, 100);
+    assert_eq!(entry.claimable, 0);
+
+    let entry = portfolio.get_unchecked(1);
+    assert_eq!(entry.campaign_id, active);
+    assert_eq!(entry.contributed, 200);
+    assert_eq!(entry.claimable, 200);
+
+    let entry = portfolio.get_unchecked(2);
+    assert_eq!(entry.status, CampaignStatus::Frozen);
+    assert_eq!(entry.claimable, 300);
+
+    client.claim_clawback(&donor, &frozen);
+    let page = client.get_donor_portfolio(&donor, &2, &5);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).refunded, 300);
+    assert_eq!(page.get_unchecked(0).claimable, 0);
+
+    assert_eq!(client.get_donor_portfolio(&donor, &1, &1).len(), 1);
+    assert_eq!(client.get_donor_portfolio(&donor, &3, &10).len(), 0);
+}
+
+#[test]
+fn test_portfolio_keeps_archived_campaigns() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+    let campaign_id = create_campaign(&env, &client, &admin, "archived", None);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+    client.withdraw(&campaign_id);
+
+    let portfolio = client.get_donor_portfolio(&donor, &0, &10);
+    assert_eq!(portfolio.len(), 1);
+
+    let entry = portfolio.get_unchecked(0);
+    assert_eq!(entry.status, CampaignStatus::Completed);
+    assert_eq!(entry.contributed, 1_000);
+    assert_eq!(entry.refunded, 0);
+    assert_eq!(entry.claimable, 0);
+}
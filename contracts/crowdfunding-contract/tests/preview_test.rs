@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{setup, validate};
+use crowdfunding_contract::storage::types::error::Error;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+#[test]
+fn test_preview_contribute_and_refund_match_real_calls() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let preview = client.preview_contribute(&campaign_id, &300);
+    assert_eq!(preview.amount, 300);
+    assert_eq!(preview.total_raised, 300);
+    assert_eq!(preview.remaining_goal, 700);
+
+    assert_eq!(
+        client.try_preview_contribute(&campaign_id, &5).err(),
+        Some(Ok(Error::ContributionBelowMinimum))
+    );
+    assert_eq!(
+        client.try_preview_contribute(&campaign_id, &1_001).err(),
+        Some(Ok(Error::CampaignGoalExceeded))
+    );
+    assert_eq!(
+        client
+            .try_preview_contribute(&String::from_str(&env, "missing"), &100)
+            .err(),
+        Some(Ok(Error::CampaignNotFound))
+    );
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &300);
+    assert_eq!(
+        client.try_preview_refund(&donor, &campaign_id).err(),
+        Some(Ok(Error::ContributionNotFound))
+    );
+    client.contribute(&donor, &campaign_id, &300, &false);
+
+    let preview = client.preview_refund(&donor, &campaign_id);
+    assert_eq!(preview.amount, 300);
+    assert_eq!(preview.total_raised, 0);
+    assert_eq!(preview.supporters, 0);
+
+    client.refund(&donor, &campaign_id);
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.total_raised, preview.total_raised);
+    assert_eq!(campaign.supporters, preview.supporters);
+    assert_eq!(
+        token::Client::new(&env, &token.address).balance(&donor),
+        preview.amount
+    );
+}
+
+#[test]
+fn test_preview_withdraw_matches_real_call() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.set_release_delay(&campaign_id, &500);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    assert_eq!(
+        client.try_preview_withdraw(&campaign_id, &1).err(),
+        Some(Ok(Error::MilestoneNotCompleted))
+    );
+
+    validate(&env, &client, &campaign_id, 1);
+    validate(&env, &client, &campaign_id, 2);
+    assert_eq!(
+        client.try_preview_withdraw(&campaign_id, &2).err(),
+        Some(Ok(Error::ReleaseLocked))
+    );
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_500);
+    let preview = client.preview_withdraw(&campaign_id, &2);
+    assert_eq!(preview.amount, 1_000);
+    assert_eq!(preview.withdrawable_amount, 0);
+    assert_eq!(preview.milestones, vec![&env, 1, 2]);
+
+    // Previewing writes nothing
+    assert_eq!(
+        client.withdraw_milestone_funds(&campaign_id, &2),
+        preview.amount
+    );
+    assert_eq!(
+        client.try_preview_withdraw(&campaign_id, &2).err(),
+        Some(Ok(Error::NoFundsToWithdraw))
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{add_proof, setup, validate};
+use crowdfunding_contract::storage::types::error::Error;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+#[test]
+fn test_release_waits_for_delay() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    client.set_release_delay(&campaign_id, &500);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    // Release terms are locked once funds are in
+    assert_eq!(
+        client.try_set_release_delay(&campaign_id, &0),
+        Err(Ok(Error::MilestonePlanLocked))
+    );
+
+    validate(&env, &client, &campaign_id, 1);
+    assert_eq!(
+        client.get_milestone(&campaign_id, &1).unlocks_at,
+        Some(1_500)
+    );
+    assert_eq!(
+        client.try_withdraw_milestone_funds(&campaign_id, &1),
+        Err(Ok(Error::ReleaseLocked))
+    );
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_500);
+    assert_eq!(client.withdraw_milestone_funds(&campaign_id, &1), 400);
+
+    // A release that already unlocked cannot be cancelled
+    assert_eq!(
+        client.try_cancel_milestone_release(
+            &campaign_id,
+            &1,
+            &String::from_str(&env, "Late objection")
+        ),
+        Err(Ok(Error::MilestoneAlreadyCompleted))
+    );
+}
+
+#[test]
+fn test_admin_cancels_pending_release() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    client.set_release_delay(&campaign_id, &500);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    validate(&env, &client, &campaign_id, 1);
+    assert_eq!(client.get_campaign(&campaign_id).withdrawable_amount, 400);
+
+    client.cancel_milestone_release(
+        &campaign_id,
+        &1,
+        &String::from_str(&env, "Forged drilling report"),
+    );
+
+    let milestone = client.get_milestone(&campaign_id, &1);
+    assert!(!milestone.completed);
+    assert_eq!(milestone.proof_id, None);
+    assert_eq!(milestone.unlocks_at, None);
+
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.current_milestone, 0);
+    assert_eq!(campaign.withdrawable_amount, 0);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_000);
+    assert_eq!(
+        client.try_withdraw_milestone_funds(&campaign_id, &1),
+        Err(Ok(Error::MilestoneNotCompleted))
+    );
+}
+
+#[test]
+fn test_cancelled_campaign_cannot_validate_milestones() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+    client.cancel_campaign(&campaign_id);
+
+    // A cancelled campaign releases nothing new to its creator
+    add_proof(&env, &client, &campaign_id, "proof-1");
+    assert_eq!(
+        client.try_validate_milestone_with_proof(
+            &campaign_id,
+            &1,
+            &String::from_str(&env, "proof-1")
+        ),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+    assert_eq!(client.get_campaign(&campaign_id).withdrawable_amount, 0);
+}
@@ -0,0 +1,184 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign, setup_contract};
+use crowdfunding_contract::{
+    storage::{
+        structs::{
+            milestone::Vesting,
+            report::{ReportCategory, ReportStatus},
+        },
+        types::error::Error,
+    },
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+fn setup(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    Address,
+    String,
+    [Address; 3],
+) {
+    let (client, token, admin) = setup_contract(env);
+    let campaign_id = create_campaign(env, &client, &admin, "library", None);
+    client.add_milestone(
+        &campaign_id,
+        &500,
+        &String::from_str(env, "Books"),
+        &None,
+        &Vec::new(env),
+        &Vec::new(env),
+        &Vesting::Immediate,
+    );
+
+    let donors = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for (donor, amount) in donors.iter().zip([600, 300, 100]) {
+        token.mint(donor, &amount);
+        client.contribute(donor, &campaign_id, &amount, &false);
+    }
+
+    (client, admin, campaign_id, donors)
+}
+
+fn report(env: &Env, client: &CrowdfundingContractClient, campaign_id: &String, donor: &Address) {
+    client.report_campaign(
+        donor,
+        campaign_id,
+        &ReportCategory::FakeProof,
+        &String::from_str(env, "ipfs://QmReceipts"),
+    );
+}
+
+#[test]
+fn test_reports_past_threshold_pause_withdrawals() {
+    let env = Env::default();
+    let (client, admin, campaign_id, donors) = setup(&env);
+
+    // 10% of donations stays below the default 20% threshold
+    report(&env, &client, &campaign_id, &donors[2]);
+    assert_eq!(
+        client.get_campaign_reports(&campaign_id).status,
+        ReportStatus::Open
+    );
+    assert!(!client.get_campaign(&campaign_id).withdrawals_paused);
+
+    assert_eq!(
+        client.try_report_campaign(
+            &donors[2],
+            &campaign_id,
+            &ReportCategory::Other,
+            &String::from_str(&env, "ipfs://QmAgain"),
+        ),
+        Err(Ok(Error::ReportAlreadyFiled))
+    );
+    assert_eq!(
+        client.try_report_campaign(
+            &Address::generate(&env),
+            &campaign_id,
+            &ReportCategory::Other,
+            &String::from_str(&env, "ipfs://QmOutsider"),
+        ),
+        Err(Ok(Error::ContributionNotFound))
+    );
+
+    report(&env, &client, &campaign_id, &donors[1]);
+    let reports = client.get_campaign_reports(&campaign_id);
+    assert_eq!(reports.status, ReportStatus::UnderReview);
+    assert_eq!(reports.pending_weight, 400);
+    assert_eq!(reports.reports.len(), 2);
+    assert_eq!(client.get_report_queue(), vec![&env, campaign_id.clone()]);
+
+    client.add_proof(
+        &String::from_str(&env, "books"),
+        &campaign_id,
+        &String::from_str(&env, "ipfs://QmBooks"),
+        &String::from_str(&env, "Book invoices"),
+        &Vec::new(&env),
+        &Vec::new(&env),
+    );
+    client.validate_milestone_with_proof(&campaign_id, &1, &String::from_str(&env, "books"));
+    assert_eq!(
+        client.try_withdraw_milestone_funds(&campaign_id, &1),
+        Err(Ok(Error::WithdrawalsPaused))
+    );
+
+    client.dismiss_reports(
+        &admin,
+        &campaign_id,
+        &String::from_str(&env, "Invoices check out"),
+    );
+    let reports = client.get_campaign_reports(&campaign_id);
+    assert_eq!(reports.status, ReportStatus::Dismissed);
+    assert_eq!(reports.resolved_by, Some(admin));
+    assert_eq!(client.get_report_queue().len(), 0);
+    assert_eq!(client.withdraw_milestone_funds(&campaign_id, &1), 500);
+}
+
+#[test]
+fn test_upheld_reports_keep_withdrawals_paused() {
+    let env = Env::default();
+    let (client, admin, campaign_id, donors) = setup(&env);
+
+    assert_eq!(
+        client.try_set_report_threshold(&0),
+        Err(Ok(Error::InvalidReportThreshold))
+    );
+    client.set_report_threshold(&5_000);
+    assert_eq!(client.get_report_threshold(), 5_000);
+
+    report(&env, &client, &campaign_id, &donors[1]);
+    assert_eq!(
+        client.get_campaign_reports(&campaign_id).status,
+        ReportStatus::Open
+    );
+
+    report(&env, &client, &campaign_id, &donors[0]);
+    assert!(client.get_campaign(&campaign_id).withdrawals_paused);
+
+    client.uphold_reports(
+        &admin,
+        &campaign_id,
+        &String::from_str(&env, "Proofs were forged"),
+    );
+
+    let creator = client.get_campaign(&campaign_id).creator;
+    assert_eq!(client.get_reputation(&creator).challenges_upheld, 1);
+    assert!(client.get_campaign(&campaign_id).withdrawals_paused);
+    assert_eq!(
+        client.try_dismiss_reports(&admin, &campaign_id, &String::from_str(&env, "")),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+}
+
+#[test]
+fn test_refunded_reporter_stops_counting() {
+    let env = Env::default();
+    let (client, _, campaign_id, donors) = setup(&env);
+    client.set_report_threshold(&5_000);
+
+    report(&env, &client, &campaign_id, &donors[1]);
+    assert_eq!(
+        client.get_campaign_reports(&campaign_id).pending_weight,
+        300
+    );
+
+    client.refund(&donors[1], &campaign_id);
+    let reports = client.get_campaign_reports(&campaign_id);
+    assert_eq!(reports.pending_weight, 0);
+    assert_eq!(reports.reports.get_unchecked(0).weight, 0);
+
+    // 100 of the 700 still raised stays below half of the donations
+    report(&env, &client, &campaign_id, &donors[2]);
+    let reports = client.get_campaign_reports(&campaign_id);
+    assert_eq!(reports.pending_weight, 100);
+    assert_eq!(reports.status, ReportStatus::Open);
+    assert!(!client.get_campaign(&campaign_id).withdrawals_paused);
+}
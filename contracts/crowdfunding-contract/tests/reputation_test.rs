@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{add_proof, create_campaign_with, setup_contract, CampaignParams};
+use crowdfunding_contract::{
+    storage::{
+        structs::{campaign::CampaignStatus, milestone::Vesting},
+        types::error::Error,
+    },
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Vec,
+};
+
+fn add_approved_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    admin: &Address,
+    creator: &Address,
+) -> String {
+    let params = CampaignParams {
+        creator: Some(creator.clone()),
+        ..CampaignParams::default()
+    };
+    create_campaign_with(env, client, admin, "clinic", params)
+}
+
+#[test]
+fn test_reputation_score_from_milestones_and_ratings() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    let creator = Address::generate(&env);
+    let campaign_id = add_approved_campaign(&env, &client, &admin, &creator);
+    client.add_milestone(
+        &campaign_id,
+        &400,
+        &String::from_str(&env, "Building"),
+        &Some(2_000),
+        &Vec::new(&env),
+        &Vec::new(&env),
+        &Vesting::Immediate,
+    );
+    client.add_milestone(
+        &campaign_id,
+        &1_000,
+        &String::from_str(&env, "Equipment"),
+        &Some(3_000),
+        &Vec::new(&env),
+        &Vec::new(&env),
+        &Vesting::Immediate,
+    );
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    // Donors can only rate once the campaign has finished
+    assert_eq!(
+        client.try_rate_campaign(&donor, &campaign_id, &5),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    add_proof(&env, &client, &campaign_id, "proof-1");
+    client.validate_milestone_with_proof(&campaign_id, &1, &String::from_str(&env, "proof-1"));
+
+    client.reject_milestone(
+        &campaign_id,
+        &2,
+        &String::from_str(&env, "Invoices are unreadable"),
+    );
+    env.ledger().with_mut(|ledger| ledger.timestamp = 4_000);
+    add_proof(&env, &client, &campaign_id, "proof-2");
+    client.validate_milestone_with_proof(&campaign_id, &2, &String::from_str(&env, "proof-2"));
+
+    assert_eq!(
+        client.try_rate_campaign(&donor, &campaign_id, &6),
+        Err(Ok(Error::InvalidRating))
+    );
+    client.rate_campaign(&donor, &campaign_id, &4);
+    client.rate_campaign(&donor, &campaign_id, &5);
+
+    let counters = client.get_reputation(&creator);
+    assert_eq!(counters.milestones_on_time, 1);
+    assert_eq!(counters.milestones_late, 1);
+    assert_eq!(counters.milestones_rejected, 1);
+    assert_eq!(counters.ratings_count, 1);
+    assert_eq!(counters.ratings_total, 5);
+
+    // 500 + 20 (on time) + 5 (late) - 30 (rejected) + 100 (five stars)
+    assert_eq!(client.reputation(&creator), 595);
+}
+
+#[test]
+fn test_cancellation_and_upheld_challenges_lower_the_score() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let creator = Address::generate(&env);
+    let campaign_id = add_approved_campaign(&env, &client, &admin, &creator);
+    assert_eq!(client.reputation(&creator), 500);
+
+    client.uphold_challenge(
+        &admin,
+        &campaign_id,
+        &String::from_str(&env, "Photos reused from another project"),
+    );
+    client.cancel_campaign(&campaign_id);
+
+    assert_eq!(
+        client.get_campaign(&campaign_id).status,
+        CampaignStatus::Cancelled
+    );
+    let donor = Address::generate(&env);
+    token.mint(&donor, &100);
+    assert_eq!(
+        client.try_contribute(&donor, &campaign_id, &100, &false),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    // 500 - 200 (challenge) - 100 (cancelled)
+    assert_eq!(client.reputation(&creator), 200);
+}
+
+#[test]
+fn test_cancelled_campaign_escrow_stays_refundable() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let creator = Address::generate(&env);
+    let campaign_id = add_approved_campaign(&env, &client, &admin, &creator);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+    client.cancel_campaign(&campaign_id);
+
+    // Reaching the goal does not let the creator take a cancelled campaign's escrow
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    client.refund(&donor, &campaign_id);
+    assert_eq!(
+        token::Client::new(&env, &token.address).balance(&donor),
+        1_000
+    );
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+mod common;
+
+use common::setup_contract;
+use crowdfunding_contract::{
+    storage::{
+        structs::{campaign::CampaignStatus, review::ReviewAction},
+        types::error::Error,
+    },
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+fn add_campaign(env: &Env, client: &CrowdfundingContractClient) -> String {
+    let campaign_id = String::from_str(env, "school-meals");
+    client.add_campaign(
+        &campaign_id,
+        &Address::generate(env),
+        &None,
+        &String::from_str(env, "School meals"),
+        &String::from_str(env, "Daily meals for 200 pupils"),
+        &10_000,
+        &10,
+        &None,
+    );
+    campaign_id
+}
+
+#[test]
+fn test_new_campaign_refuses_donations_until_approved() {
+    let env = Env::default();
+    let (client, token, _) = setup_contract(&env);
+    let campaign_id = add_campaign(&env, &client);
+
+    assert_eq!(
+        client.get_campaign(&campaign_id).status,
+        CampaignStatus::PendingReview
+    );
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &100);
+    assert_eq!(
+        client.try_contribute(&donor, &campaign_id, &100, &false),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    let moderator = Address::generate(&env);
+    client.add_moderator(&moderator);
+    client.approve_campaign(&moderator, &campaign_id);
+    client.contribute(&donor, &campaign_id, &100, &false);
+
+    assert_eq!(client.get_campaign(&campaign_id).total_raised, 100);
+}
+
+#[test]
+fn test_review_history_records_change_requests() {
+    let env = Env::default();
+    let (client, _, _) = setup_contract(&env);
+    let campaign_id = add_campaign(&env, &client);
+
+    let moderator = Address::generate(&env);
+    client.add_moderator(&moderator);
+    client.request_campaign_changes(
+        &moderator,
+        &campaign_id,
+        &String::from_str(&env, "Attach the school registration"),
+    );
+
+    // Approval is only possible once the creator resubmits
+    assert_eq!(
+        client.try_approve_campaign(&moderator, &campaign_id),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    client.resubmit_campaign(
+        &campaign_id,
+        &String::from_str(&env, "School meals"),
+        &String::from_str(&env, "Daily meals for 200 pupils, registration #1234"),
+    );
+    client.reject_campaign(
+        &moderator,
+        &campaign_id,
+        &String::from_str(&env, "Registration could not be verified"),
+    );
+
+    let reviews = client.get_campaign_reviews(&campaign_id);
+    assert_eq!(reviews.len(), 3);
+    assert_eq!(
+        reviews.get(0).unwrap().action,
+        ReviewAction::ChangesRequested
+    );
+    assert_eq!(reviews.get(1).unwrap().action, ReviewAction::Resubmitted);
+    assert_eq!(reviews.get(2).unwrap().action, ReviewAction::Rejected);
+    assert_eq!(reviews.get(2).unwrap().reviewer, moderator);
+    assert_eq!(
+        client.get_campaign(&campaign_id).status,
+        CampaignStatus::Rejected
+    );
+}
+
+#[test]
+fn test_only_moderators_can_review() {
+    let env = Env::default();
+    let (client, _, _) = setup_contract(&env);
+    let campaign_id = add_campaign(&env, &client);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_approve_campaign(&stranger, &campaign_id),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,154 @@
+#![cfg(test)]
+
+mod common;
+
+use common::add_proof;
+use crowdfunding_contract::{storage::types::error::Error, CrowdfundingContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, String) {
+    let (client, token, campaign_id) = common::setup(env);
+
+    let donor = Address::generate(env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    (client, campaign_id)
+}
+
+#[test]
+fn test_revoking_unreleased_proof_rolls_back_milestones() {
+    let env = Env::default();
+    let (client, campaign_id) = setup(&env);
+    let proof_1 = String::from_str(&env, "proof-1");
+
+    add_proof(&env, &client, &campaign_id, "proof-1");
+    add_proof(&env, &client, &campaign_id, "proof-2");
+    client.validate_milestone_with_proof(&campaign_id, &1, &proof_1);
+    client.validate_milestone_with_proof(&campaign_id, &2, &String::from_str(&env, "proof-2"));
+    assert_eq!(client.get_campaign(&campaign_id).withdrawable_amount, 1_000);
+
+    let reason = String::from_str(&env, "Photos were of another bridge");
+    client.revoke_proof(&campaign_id, &proof_1, &reason);
+
+    // The second milestone built on the first and rolls back with it
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.current_milestone, 0);
+    assert_eq!(campaign.withdrawable_amount, 0);
+    assert!(!client.get_milestone(&campaign_id, &1).completed);
+    assert!(!client.get_milestone(&campaign_id, &2).completed);
+    let reputation = client.get_reputation(&campaign.creator);
+    assert_eq!(reputation.milestones_rejected, 2);
+    assert_eq!(reputation.milestones_on_time, 0);
+
+    // The proof stays on record, marked as revoked
+    let proof = client.get_proof(&campaign_id, &proof_1);
+    assert!(proof.revoked_at.is_some());
+    assert_eq!(proof.revocation_reason, Some(reason.clone()));
+    assert_eq!(
+        client.try_validate_milestone_with_proof(&campaign_id, &1, &proof_1),
+        Err(Ok(Error::ProofRevoked))
+    );
+    assert_eq!(
+        client.try_revoke_proof(&campaign_id, &proof_1, &reason),
+        Err(Ok(Error::ProofRevoked))
+    );
+    assert_eq!(
+        client.try_add_proof(
+            &proof_1,
+            &campaign_id,
+            &String::from_str(&env, "ipfs://QmOther"),
+            &String::from_str(&env, "Replacement"),
+            &Vec::new(&env),
+            &Vec::new(&env),
+        ),
+        Err(Ok(Error::ProofRevoked))
+    );
+
+    // Validating again with a new proof credits the milestone only once
+    add_proof(&env, &client, &campaign_id, "proof-3");
+    client.validate_milestone_with_proof(&campaign_id, &1, &String::from_str(&env, "proof-3"));
+    assert_eq!(
+        client.get_reputation(&campaign.creator).milestones_on_time,
+        1
+    );
+}
+
+#[test]
+fn test_revoking_released_proof_flags_fraud() {
+    let env = Env::default();
+    let (client, campaign_id) = setup(&env);
+    let proof_1 = String::from_str(&env, "proof-1");
+
+    add_proof(&env, &client, &campaign_id, "proof-1");
+    client.validate_milestone_with_proof(&campaign_id, &1, &proof_1);
+    assert_eq!(client.withdraw_milestone_funds(&campaign_id, &1), 400);
+
+    client.revoke_proof(
+        &campaign_id,
+        &proof_1,
+        &String::from_str(&env, "Invoices were forged"),
+    );
+
+    // Released funds cannot be undone, so the creator is flagged instead
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.current_milestone, 1);
+    assert!(client.get_milestone(&campaign_id, &1).completed);
+    assert_eq!(client.get_reputation(&campaign.creator).fraud_flags, 1);
+    assert!(client
+        .get_proof(&campaign_id, &proof_1)
+        .revoked_at
+        .is_some());
+}
+
+#[test]
+fn test_revoking_reused_proof_rolls_back_from_first_milestone() {
+    let env = Env::default();
+    let (client, campaign_id) = setup(&env);
+    let proof_1 = String::from_str(&env, "proof-1");
+
+    add_proof(&env, &client, &campaign_id, "proof-1");
+    client.validate_milestone_with_proof(&campaign_id, &1, &proof_1);
+    client.validate_milestone_with_proof(&campaign_id, &2, &proof_1);
+
+    client.revoke_proof(
+        &campaign_id,
+        &proof_1,
+        &String::from_str(&env, "Photos were of another bridge"),
+    );
+
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.current_milestone, 0);
+    assert_eq!(campaign.withdrawable_amount, 0);
+    assert!(!client.get_milestone(&campaign_id, &1).completed);
+    assert_eq!(
+        client.get_reputation(&campaign.creator).milestones_rejected,
+        2
+    );
+}
+
+#[test]
+fn test_existing_proof_cannot_be_replaced() {
+    let env = Env::default();
+    let (client, campaign_id) = setup(&env);
+    add_proof(&env, &client, &campaign_id, "receipts");
+
+    // Replacing a proof would change what an admin already reviewed
+    assert_eq!(
+        client.try_add_proof(
+            &String::from_str(&env, "receipts"),
+            &campaign_id,
+            &String::from_str(&env, "ipfs://QmOther"),
+            &String::from_str(&env, "Replacement"),
+            &Vec::new(&env),
+            &Vec::new(&env),
+        ),
+        Err(Ok(Error::ProofAlreadyExists))
+    );
+    assert_eq!(
+        client
+            .get_proof(&campaign_id, &String::from_str(&env, "receipts"))
+            .uri,
+        String::from_str(&env, "ipfs://QmWell")
+    );
+}
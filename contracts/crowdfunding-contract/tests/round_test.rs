@@ -0,0 +1,210 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign_with, setup_contract, CampaignParams};
+use crowdfunding_contract::{
+    storage::{structs::round::RoundStatus, types::error::Error},
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    admin: &Address,
+    id: &str,
+) -> String {
+    let params = CampaignParams {
+        goal: 10_000,
+        ..CampaignParams::default()
+    };
+    create_campaign_with(env, client, admin, id, params)
+}
+
+fn fund_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token: &token::StellarAssetClient,
+    campaign_id: &String,
+    gifts: &[i128],
+) {
+    for gift in gifts {
+        let donor = Address::generate(env);
+        token.mint(&donor, gift);
+        client.contribute(&donor, campaign_id, gift, &false);
+    }
+}
+
+#[test]
+fn test_round_matches_broad_support() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let broad = create_campaign(&env, &client, &admin, "broad-campaign");
+    let narrow = create_campaign(&env, &client, &admin, "narrow-campaign");
+
+    // Same total raised, but only the first campaign has several donors
+    fund_campaign(&env, &client, &token, &broad, &[100, 100, 100, 100]);
+    fund_campaign(&env, &client, &token, &narrow, &[400]);
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    client.enroll_campaign(&round_id, &broad);
+    client.enroll_campaign(&round_id, &narrow);
+
+    let funder = Address::generate(&env);
+    token.mint(&funder, &1_000);
+    client.fund_round(&funder, &round_id, &1_000);
+    client.finalize_round(&round_id);
+
+    let round = client.get_round(&round_id);
+    assert_eq!(round.status, RoundStatus::Finalized);
+    assert_eq!(round.distributed, 1_000);
+    assert_eq!(round.matches.get(broad.clone()), Some(1_000));
+    assert_eq!(round.matches.get(narrow.clone()), None);
+
+    let campaign = client.get_campaign(&broad);
+    assert_eq!(campaign.matched_amount, 1_000);
+    assert_eq!(campaign.total_raised, 1_400);
+    assert_eq!(client.get_campaign(&narrow).total_raised, 400);
+}
+
+#[test]
+fn test_round_cannot_be_finalized_twice() {
+    let env = Env::default();
+    let (client, token, _) = setup_contract(&env);
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    client.finalize_round(&round_id);
+
+    assert_eq!(
+        client.try_finalize_round(&round_id),
+        Err(Ok(Error::InvalidRoundState))
+    );
+
+    let funder = Address::generate(&env);
+    token.mint(&funder, &1_000);
+    assert_eq!(
+        client.try_fund_round(&funder, &round_id, &1_000),
+        Err(Ok(Error::InvalidRoundState))
+    );
+}
+
+#[test]
+fn test_round_skips_campaigns_closed_after_enrolment() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    let [open, archived, pending] = ["open", "archived", "pending"].map(|id| {
+        let campaign_id = String::from_str(&env, id);
+        client.add_campaign(
+            &campaign_id,
+            &Address::generate(&env),
+            &None,
+            &String::from_str(&env, "Campaign"),
+            &String::from_str(&env, "Matching round test"),
+            &200,
+            &10,
+            &None,
+        );
+        campaign_id
+    });
+
+    assert_eq!(
+        client.try_enroll_campaign(&round_id, &pending),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    for campaign_id in [&open, &archived] {
+        client.approve_campaign(&admin, campaign_id);
+        fund_campaign(&env, &client, &token, campaign_id, &[100, 100]);
+        client.enroll_campaign(&round_id, campaign_id);
+    }
+
+    // Paying out a campaign archives it while it is still enrolled
+    client.withdraw(&archived);
+
+    let funder = Address::generate(&env);
+    token.mint(&funder, &500);
+    client.fund_round(&funder, &round_id, &500);
+    client.finalize_round(&round_id);
+
+    let round = client.get_round(&round_id);
+    assert_eq!(round.matches.get(open.clone()), Some(500));
+    assert_eq!(round.matches.get(archived), None);
+    assert_eq!(client.get_campaign(&open).matched_amount, 500);
+}
+
+#[test]
+fn test_unmatched_pool_returns_to_funders() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let campaign_id = create_campaign(&env, &client, &admin, "solo");
+    fund_campaign(&env, &client, &token, &campaign_id, &[400]);
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    client.enroll_campaign(&round_id, &campaign_id);
+
+    let funders = [Address::generate(&env), Address::generate(&env)];
+    for (funder, amount) in funders.iter().zip([300, 200]) {
+        token.mint(funder, &amount);
+        client.fund_round(funder, &round_id, &amount);
+    }
+
+    // A single donor gives the campaign no quadratic weight
+    client.finalize_round(&round_id);
+    assert_eq!(client.get_round(&round_id).distributed, 0);
+
+    let balances = token::Client::new(&env, &token.address);
+    assert_eq!(balances.balance(&funders[0]), 300);
+    assert_eq!(balances.balance(&funders[1]), 200);
+    assert_eq!(client.reconcile().surplus, 0);
+}
+
+#[test]
+fn test_cancelled_campaign_hands_match_back_to_funders() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let campaign_id = create_campaign(&env, &client, &admin, "shelter");
+    let donors = [Address::generate(&env), Address::generate(&env)];
+    for donor in donors.iter() {
+        token.mint(donor, &200);
+        client.contribute(donor, &campaign_id, &200, &false);
+    }
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    client.enroll_campaign(&round_id, &campaign_id);
+    let funders = [Address::generate(&env), Address::generate(&env)];
+    for (funder, amount) in funders.iter().zip([600, 400]) {
+        token.mint(funder, &amount);
+        client.fund_round(funder, &round_id, &amount);
+    }
+    client.finalize_round(&round_id);
+    assert_eq!(client.get_campaign(&campaign_id).matched_amount, 1_000);
+
+    // Nothing was spent, so the whole match goes back to the round
+    client.cancel_campaign(&campaign_id);
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.matched_amount, 0);
+    assert_eq!(campaign.total_raised, 400);
+    assert_eq!(client.get_round(&round_id).returned, 1_000);
+    assert_eq!(client.reconcile().surplus, 0);
+
+    assert_eq!(client.claim_round_return(&funders[0], &round_id), 600);
+    assert_eq!(client.claim_round_return(&funders[1], &round_id), 400);
+    assert_eq!(
+        client.try_claim_round_return(&funders[0], &round_id),
+        Err(Ok(Error::NoFundsToWithdraw))
+    );
+
+    // Contributors still get their own funds back in full
+    for donor in donors.iter() {
+        client.refund(donor, &campaign_id);
+    }
+    let balances = token::Client::new(&env, &token.address);
+    assert_eq!(balances.balance(&donors[0]), 200);
+    assert_eq!(balances.balance(&client.address), 0);
+}
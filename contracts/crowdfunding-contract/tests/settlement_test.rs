@@ -0,0 +1,274 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{add_milestones, add_proof, create_campaign, setup_contract, validate};
+use crowdfunding_contract::{
+    storage::{structs::campaign::CampaignStatus, types::error::Error},
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup(
+    env: &Env,
+    fallback_beneficiary: Option<Address>,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    String,
+) {
+    let (client, token, admin) = setup_contract(env);
+    let campaign_id = create_campaign(env, &client, &admin, "school", fallback_beneficiary);
+    add_milestones(env, &client, &campaign_id, &[300, 700]);
+
+    (client, token, campaign_id)
+}
+
+fn complete_milestones(env: &Env, client: &CrowdfundingContractClient, campaign_id: &String) {
+    for sequence in 1..=2u32 {
+        validate(env, client, campaign_id, sequence);
+    }
+}
+
+#[test]
+fn test_leftover_is_returned_pro_rata() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env, None);
+    let balance = token::Client::new(&env, &token.address);
+
+    let donors = [Address::generate(&env), Address::generate(&env)];
+    for (donor, amount) in donors.iter().zip([600, 400]) {
+        token.mint(donor, &amount);
+        client.contribute(donor, &campaign_id, &amount, &false);
+    }
+
+    // Milestones only need 700 of the 1000 raised
+    complete_milestones(&env, &client, &campaign_id);
+
+    assert_eq!(
+        client.try_finalize_campaign(&campaign_id).err(),
+        Some(Ok(Error::InvalidCampaignStatus))
+    );
+
+    // Both validated tranches are paid out together
+    let creator = client.get_campaign(&campaign_id).creator;
+    assert_eq!(client.withdraw_milestone_funds(&campaign_id, &2), 700);
+    assert_eq!(balance.balance(&creator), 700);
+
+    let settlement = client.finalize_campaign(&campaign_id);
+    assert_eq!(settlement.released_amount, 700);
+    assert_eq!(settlement.leftover_amount, 300);
+    assert_eq!(settlement.leftover_recipient, None);
+
+    assert_eq!(
+        client.get_campaign(&campaign_id).status,
+        CampaignStatus::Completed
+    );
+    assert_eq!(
+        client.try_refund(&donors[0], &campaign_id),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    // Each contributor claims their share of the leftover instead
+    assert_eq!(client.claim_leftover(&donors[0], &campaign_id), 180);
+    assert_eq!(client.claim_leftover(&donors[1], &campaign_id), 120);
+    assert_eq!(balance.balance(&donors[0]), 180);
+    assert_eq!(balance.balance(&donors[1]), 120);
+    assert_eq!(
+        client.try_claim_leftover(&donors[0], &campaign_id),
+        Err(Ok(Error::ContributionNotFound))
+    );
+}
+
+#[test]
+fn test_leftover_goes_to_fallback_beneficiary() {
+    let env = Env::default();
+    let beneficiary = Address::generate(&env);
+    let (client, token, campaign_id) = setup(&env, Some(beneficiary.clone()));
+    let balance = token::Client::new(&env, &token.address);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+    assert_eq!(
+        client.try_finalize_campaign(&campaign_id).err(),
+        Some(Ok(Error::MilestoneNotCompleted))
+    );
+
+    complete_milestones(&env, &client, &campaign_id);
+    client.withdraw_milestone_funds(&campaign_id, &2);
+
+    let settlement = client.finalize_campaign(&campaign_id);
+    assert_eq!(settlement.leftover_amount, 300);
+    assert_eq!(settlement.leftover_recipient, Some(beneficiary.clone()));
+    assert_eq!(balance.balance(&beneficiary), 300);
+    assert_eq!(balance.balance(&donor), 0);
+    assert_eq!(
+        client.try_claim_leftover(&donor, &campaign_id),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    assert_eq!(
+        client.get_settlement(&campaign_id).settled_at,
+        settlement.settled_at
+    );
+}
+
+#[test]
+fn test_withdraw_archives_the_campaign() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+
+    let campaign_id = String::from_str(&env, "festival");
+    let creator = Address::generate(&env);
+    client.add_campaign(
+        &campaign_id,
+        &creator,
+        &None,
+        &String::from_str(&env, "Festival"),
+        &String::from_str(&env, "Neighbourhood festival"),
+        &500,
+        &10,
+        &None,
+    );
+    client.approve_campaign(&admin, &campaign_id);
+
+    for amount in [300, 200] {
+        let donor = Address::generate(&env);
+        token.mint(&donor, &amount);
+        client.contribute(&donor, &campaign_id, &amount, &false);
+    }
+    add_proof(&env, &client, &campaign_id, "tickets");
+
+    client.withdraw(&campaign_id);
+
+    // The bulky record is pruned but the settlement stays auditable
+    assert_eq!(
+        client.try_get_campaign(&campaign_id).err(),
+        Some(Ok(Error::CampaignNotFound))
+    );
+    let settlement = client.get_settlement(&campaign_id);
+    assert_eq!(settlement.payout_address, creator);
+    assert_eq!(settlement.total_raised, 500);
+    assert_eq!(settlement.released_amount, 500);
+    assert_eq!(settlement.supporters, 2);
+    assert_eq!(
+        settlement.proof_ids,
+        vec![&env, String::from_str(&env, "tickets")]
+    );
+
+    // Archived ids cannot be reused for a new campaign
+    assert_eq!(
+        client.try_add_campaign(
+            &campaign_id,
+            &creator,
+            &None,
+            &String::from_str(&env, "Festival"),
+            &String::from_str(&env, "Neighbourhood festival"),
+            &500,
+            &10,
+            &None,
+        ),
+        Err(Ok(Error::CampaignAlreadyExists))
+    );
+}
+
+#[test]
+fn test_refunded_contributor_is_left_out_of_leftover() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env, None);
+    let balance = token::Client::new(&env, &token.address);
+
+    let donors = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for (donor, amount) in donors.iter().zip([100, 500, 400]) {
+        token.mint(donor, &amount);
+        client.contribute(donor, &campaign_id, &amount, &false);
+    }
+    client.refund(&donors[0], &campaign_id);
+
+    complete_milestones(&env, &client, &campaign_id);
+    client.withdraw_milestone_funds(&campaign_id, &2);
+
+    // The 200 left is split 500:400, with the rounding dust to the last claim
+    let settlement = client.finalize_campaign(&campaign_id);
+    assert_eq!(settlement.leftover_amount, 200);
+    assert_eq!(
+        client.try_claim_leftover(&donors[0], &campaign_id),
+        Err(Ok(Error::ContributionNotFound))
+    );
+    assert_eq!(client.claim_leftover(&donors[1], &campaign_id), 111);
+    assert_eq!(client.claim_leftover(&donors[2], &campaign_id), 89);
+    assert_eq!(balance.balance(&donors[0]), 100);
+    assert_eq!(balance.balance(&client.address), 0);
+}
+
+#[test]
+fn test_finalized_campaign_cannot_be_withdrawn_again() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env, None);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    // Milestone campaigns only pay out tranche by tranche
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    complete_milestones(&env, &client, &campaign_id);
+    client.withdraw_milestone_funds(&campaign_id, &2);
+    client.finalize_campaign(&campaign_id);
+
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+
+    // The leftover stays owed to the donor until claimed
+    assert_eq!(client.reconcile().liabilities, 300);
+    client.claim_leftover(&donor, &campaign_id);
+    let reconciliation = client.reconcile();
+    assert_eq!(reconciliation.balance, 0);
+    assert_eq!(reconciliation.liabilities, 0);
+}
+
+#[test]
+fn test_finalize_hands_matched_leftover_back_to_round() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env, None);
+    let balance = token::Client::new(&env, &token.address);
+
+    let donors = [Address::generate(&env), Address::generate(&env)];
+    for donor in donors.iter() {
+        token.mint(donor, &200);
+        client.contribute(donor, &campaign_id, &200, &false);
+    }
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    client.enroll_campaign(&round_id, &campaign_id);
+    let funder = Address::generate(&env);
+    token.mint(&funder, &600);
+    client.fund_round(&funder, &round_id, &600);
+    client.finalize_round(&round_id);
+
+    // 1000 raised, 600 of it matched, and milestones only need 700
+    complete_milestones(&env, &client, &campaign_id);
+    client.withdraw_milestone_funds(&campaign_id, &2);
+
+    // 60% of the 300 left was matched and goes back to the round
+    let settlement = client.finalize_campaign(&campaign_id);
+    assert_eq!(settlement.released_amount, 700);
+    assert_eq!(settlement.leftover_amount, 120);
+    assert_eq!(client.get_round(&round_id).returned, 180);
+    assert_eq!(client.claim_round_return(&funder, &round_id), 180);
+
+    assert_eq!(client.claim_leftover(&donors[0], &campaign_id), 60);
+    assert_eq!(client.claim_leftover(&donors[1], &campaign_id), 60);
+    assert_eq!(balance.balance(&client.address), 0);
+}
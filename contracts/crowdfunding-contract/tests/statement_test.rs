@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign_with, setup_contract, CampaignParams};
+use crowdfunding_contract::CrowdfundingContractClient;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
+
+// 2024-12-31T23:59:59Z and the following second
+const LAST_SECOND_OF_2024: u64 = 1_735_689_599;
+const FIRST_SECOND_OF_2025: u64 = 1_735_689_600;
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    admin: &Address,
+    id: &str,
+    creator: &Address,
+    organization_id: Option<u32>,
+) -> String {
+    let params = CampaignParams {
+        creator: Some(creator.clone()),
+        organization_id,
+        goal: 10_000,
+        ..CampaignParams::default()
+    };
+    create_campaign_with(env, client, admin, id, params)
+}
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|ledger| ledger.timestamp = timestamp);
+}
+
+#[test]
+fn test_statement_splits_years_on_ledger_time() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+    let campaign_id = create_campaign(
+        &env,
+        &client,
+        &admin,
+        "clinic",
+        &Address::generate(&env),
+        None,
+    );
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+
+    set_time(&env, LAST_SECOND_OF_2024);
+    client.contribute(&donor, &campaign_id, &400, &false);
+
+    set_time(&env, FIRST_SECOND_OF_2025);
+    client.contribute(&donor, &campaign_id, &100, &false);
+    client.cancel_campaign(&campaign_id);
+    client.refund(&donor, &campaign_id);
+
+    let statement = client.get_giving_statement(&donor, &2024);
+    assert_eq!(statement.year, 2024);
+    assert_eq!(statement.lines.len(), 1);
+    assert_eq!(statement.total_contributed, 400);
+    assert_eq!(statement.total_refunded, 0);
+    assert_eq!(statement.total_net, 400);
+
+    // The refund of both gifts lands in the year it was paid
+    let statement = client.get_giving_statement(&donor, &2025);
+    let line = statement.lines.get_unchecked(0);
+    assert_eq!(line.campaign_id, campaign_id);
+    assert_eq!(line.contributed, 100);
+    assert_eq!(line.refunded, 500);
+    assert_eq!(line.net, -400);
+    assert_eq!(statement.total_net, -400);
+
+    let statement = client.get_giving_statement(&donor, &2023);
+    assert_eq!(statement.lines.len(), 0);
+    assert_eq!(statement.total_net, 0);
+}
+
+#[test]
+fn test_statement_totals_per_organization() {
+    let env = Env::default();
+    let (client, token, admin) = setup_contract(&env);
+    set_time(&env, FIRST_SECOND_OF_2025);
+
+    let controller = Address::generate(&env);
+    let organization_id = client.register_organization(
+        &controller,
+        &String::from_str(&env, "Water For All"),
+        &BytesN::from_array(&env, &[7u8; 32]),
+        &String::from_str(&env, "https://waterforall.org"),
+    );
+    let wells = create_campaign(
+        &env,
+        &client,
+        &admin,
+        "wells",
+        &controller,
+        Some(organization_id),
+    );
+    let pumps = create_campaign(
+        &env,
+        &client,
+        &admin,
+        "pumps",
+        &controller,
+        Some(organization_id),
+    );
+    let choir = create_campaign(&env, &client, &admin, "choir", &controller, None);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &wells, &300, &false);
+    client.contribute(&donor, &pumps, &200, &true);
+    client.contribute(&donor, &choir, &50, &false);
+
+    let statement = client.get_giving_statement(&donor, &2025);
+    assert_eq!(statement.donor, donor);
+    assert_eq!(statement.lines.len(), 3);
+    assert_eq!(
+        statement.lines.get_unchecked(1).organization_id,
+        Some(organization_id)
+    );
+    assert_eq!(statement.lines.get_unchecked(2).organization_id, None);
+    assert_eq!(statement.total_contributed, 550);
+
+    assert_eq!(statement.organizations.len(), 1);
+    let total = statement.organizations.get_unchecked(0);
+    assert_eq!(total.organization_id, organization_id);
+    assert_eq!(total.net, 500);
+}
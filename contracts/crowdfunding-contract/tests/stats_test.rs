@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign_with, setup_contract, CampaignParams};
+use crowdfunding_contract::{storage::structs::milestone::Vesting, CrowdfundingContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String, Vec,
+};
+
+fn setup(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    String,
+) {
+    let (client, token, admin) = setup_contract(env);
+    let params = CampaignParams {
+        min_donation: 5,
+        ..CampaignParams::default()
+    };
+    let campaign_id = create_campaign_with(env, &client, &admin, "garden", params);
+
+    for (target, description, due_date) in [(400, "Beds", 5_000), (1_000, "Tools", 9_000)] {
+        client.add_milestone(
+            &campaign_id,
+            &target,
+            &String::from_str(env, description),
+            &Some(due_date),
+            &Vec::new(env),
+            &Vec::new(env),
+            &Vesting::Immediate,
+        );
+    }
+
+    (client, token, campaign_id)
+}
+
+fn give(
+    client: &CrowdfundingContractClient,
+    token: &token::StellarAssetClient,
+    campaign_id: &String,
+    donor: &Address,
+    amount: i128,
+) {
+    token.mint(donor, &amount);
+    client.contribute(donor, campaign_id, &amount, &false);
+}
+
+#[test]
+fn test_stats_follow_gifts_and_releases() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+
+    let (ana, ben, cleo) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    give(&client, &token, &campaign_id, &ana, 5);
+    give(&client, &token, &campaign_id, &ben, 50);
+    give(&client, &token, &campaign_id, &ana, 50);
+    give(&client, &token, &campaign_id, &cleo, 895);
+
+    let stats = client.get_campaign_stats(&campaign_id);
+    assert_eq!(stats.unique_donors, 3);
+    assert_eq!(stats.contributions, 4);
+    assert_eq!(stats.average_gift, 250);
+    assert_eq!(stats.gift_buckets, vec![&env, 1, 2, 1]);
+    assert_eq!(stats.median_gift_bucket, 10);
+    assert_eq!(stats.progress_bps, 10_000);
+    assert_eq!(stats.escrowed_amount, 1_000);
+    assert_eq!(stats.time_left, Some(4_000));
+
+    let proof_id = String::from_str(&env, "beds-built");
+    client.add_proof(
+        &proof_id,
+        &campaign_id,
+        &String::from_str(&env, "ipfs://QmBeds"),
+        &String::from_str(&env, "Photos of the beds"),
+        &Vec::new(&env),
+        &Vec::new(&env),
+    );
+    client.validate_milestone_with_proof(&campaign_id, &1, &proof_id);
+    assert_eq!(
+        client.get_campaign_stats(&campaign_id).withdrawable_amount,
+        400
+    );
+
+    client.withdraw_milestone_funds(&campaign_id, &1);
+
+    let stats = client.get_campaign_stats(&campaign_id);
+    assert_eq!(stats.released_amount, 400);
+    assert_eq!(stats.escrowed_amount, 600);
+    assert_eq!(stats.withdrawable_amount, 0);
+    assert_eq!(stats.time_left, Some(8_000));
+}
+
+#[test]
+fn test_stats_track_refunds() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    let (ana, ben) = (Address::generate(&env), Address::generate(&env));
+    give(&client, &token, &campaign_id, &ana, 200);
+    give(&client, &token, &campaign_id, &ben, 100);
+    assert_eq!(client.get_campaign_stats(&campaign_id).progress_bps, 3_000);
+
+    client.cancel_campaign(&campaign_id);
+    client.refund(&ana, &campaign_id);
+
+    let stats = client.get_campaign_stats(&campaign_id);
+    assert_eq!(stats.unique_donors, 1);
+    assert_eq!(stats.contributions, 2);
+    assert_eq!(stats.refunded_amount, 200);
+    assert_eq!(stats.escrowed_amount, 100);
+    assert_eq!(stats.progress_bps, 1_000);
+}
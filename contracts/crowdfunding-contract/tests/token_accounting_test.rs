@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign_with, register_contract, CampaignParams};
+use crowdfunding_contract::{storage::types::error::Error, CrowdfundingContractClient};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, IssuerFlags},
+    token, Address, Env, String,
+};
+
+#[contracttype]
+enum FeeTokenKey {
+    Balance(Address),
+}
+
+/// Token that burns 1% of every transfer
+#[contract]
+struct FeeToken;
+
+#[contractimpl]
+impl FeeToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&FeeTokenKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&FeeTokenKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .persistent()
+            .set(&FeeTokenKey::Balance(from), &(from_balance - amount));
+        Self::mint(env, to, amount - amount / 100);
+    }
+}
+
+fn create_campaign(env: &Env, token: &Address) -> (CrowdfundingContractClient<'static>, String) {
+    let (client, admin) = register_contract(env, token);
+    let params = CampaignParams {
+        goal: 10_000,
+        ..CampaignParams::default()
+    };
+    let campaign_id = create_campaign_with(env, &client, &admin, "pantry", params);
+
+    (client, campaign_id)
+}
+
+#[test]
+fn test_fee_on_transfer_credits_received_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let token_id = env.register(FeeToken, ());
+    let token = FeeTokenClient::new(&env, &token_id);
+    let (client, campaign_id) = create_campaign(&env, &token_id);
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    // The token kept 10 of the 1000 sent
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.total_raised, 990);
+    assert_eq!(token.balance(&client.address), 990);
+
+    let reconciliation = client.reconcile();
+    assert_eq!(reconciliation.liabilities, 990);
+    assert_eq!(reconciliation.surplus, 0);
+
+    let round_id = client.create_round(&String::from_str(&env, "Round 1"));
+    let funder = Address::generate(&env);
+    token.mint(&funder, &500);
+    client.fund_round(&funder, &round_id, &500);
+    assert_eq!(client.get_round(&round_id).matching_pool, 495);
+
+    // Escrow covers the refund of what was credited
+    client.refund(&donor, &campaign_id);
+    assert_eq!(client.reconcile().liabilities, 495);
+    assert_eq!(client.reconcile().surplus, 0);
+}
+
+#[test]
+fn test_clawback_shows_as_shortfall() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    asset.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    let token = token::StellarAssetClient::new(&env, &asset.address());
+    let (client, campaign_id) = create_campaign(&env, &asset.address());
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    token.clawback(&client.address, &300);
+
+    // Liabilities are unchanged, so the clawed back escrow is a shortfall
+    let reconciliation = client.reconcile();
+    assert_eq!(reconciliation.balance, 700);
+    assert_eq!(reconciliation.liabilities, 1_000);
+    assert_eq!(reconciliation.surplus, -300);
+
+    let second = Address::generate(&env);
+    token.mint(&second, &200);
+    client.contribute(&second, &campaign_id, &200, &false);
+    assert_eq!(client.get_campaign(&campaign_id).total_raised, 1_200);
+    assert_eq!(client.reconcile().surplus, -300);
+}
+
+#[test]
+fn test_attributed_clawback_is_shared_by_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    asset.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    let token = token::StellarAssetClient::new(&env, &asset.address());
+    let (client, campaign_id) = create_campaign(&env, &asset.address());
+
+    let donors = [Address::generate(&env), Address::generate(&env)];
+    for (donor, amount) in donors.iter().zip([600, 400]) {
+        token.mint(donor, &amount);
+        client.contribute(donor, &campaign_id, &amount, &false);
+    }
+    token.clawback(&client.address, &300);
+
+    // Only the actual shortfall can be charged to the campaign
+    assert_eq!(client.attribute_clawback(&campaign_id, &500), 300);
+    assert_eq!(client.reconcile().surplus, 0);
+    assert_eq!(
+        client.try_attribute_clawback(&campaign_id, &100),
+        Err(Ok(Error::NoFundsToWithdraw))
+    );
+
+    // Refunds share the 700 left in escrow
+    client.refund(&donors[0], &campaign_id);
+    client.refund(&donors[1], &campaign_id);
+    let balance = token::Client::new(&env, &asset.address());
+    assert_eq!(balance.balance(&donors[0]), 420);
+    assert_eq!(balance.balance(&donors[1]), 280);
+    assert_eq!(balance.balance(&client.address), 0);
+}
+
+#[test]
+fn test_withdraw_pays_escrow_left_after_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    asset.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    let token = token::StellarAssetClient::new(&env, &asset.address());
+    let (client, campaign_id) = create_campaign(&env, &asset.address());
+    let creator = client.get_campaign(&campaign_id).creator;
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &10_000);
+    client.contribute(&donor, &campaign_id, &10_000, &false);
+    token.clawback(&client.address, &1_000);
+    client.attribute_clawback(&campaign_id, &1_000);
+
+    // The goal was reached, but only the escrow left can be paid
+    client.withdraw(&campaign_id);
+    let balance = token::Client::new(&env, &asset.address());
+    assert_eq!(balance.balance(&creator), 9_000);
+    assert_eq!(balance.balance(&client.address), 0);
+    assert_eq!(client.get_settlement(&campaign_id).released_amount, 9_000);
+}
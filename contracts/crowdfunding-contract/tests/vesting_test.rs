@@ -0,0 +1,164 @@
+#![cfg(test)]
+
+mod common;
+
+use common::{create_campaign, setup_contract, validate};
+use crowdfunding_contract::{
+    storage::{
+        structs::milestone::{Vesting, VestingSchedule},
+        types::error::Error,
+    },
+    CrowdfundingContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Vec,
+};
+
+fn setup(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    token::StellarAssetClient<'_>,
+    String,
+) {
+    let (client, token, admin) = setup_contract(env);
+    let campaign_id = create_campaign(env, &client, &admin, "farm", None);
+
+    (client, token, campaign_id)
+}
+
+fn add_milestone(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    campaign_id: &String,
+    target_amount: i128,
+    vesting: Vesting,
+) -> Result<u32, Error> {
+    match client.try_add_milestone(
+        campaign_id,
+        &target_amount,
+        &String::from_str(env, "Season"),
+        &None,
+        &Vec::new(env),
+        &Vec::new(env),
+        &vesting,
+    ) {
+        Ok(sequence) => Ok(sequence.unwrap()),
+        Err(error) => Err(error.unwrap()),
+    }
+}
+
+#[test]
+fn test_tranche_vests_linearly_after_cliff() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 500);
+
+    add_milestone(&env, &client, &campaign_id, 400, Vesting::Immediate).unwrap();
+    add_milestone(
+        &env,
+        &client,
+        &campaign_id,
+        1_000,
+        Vesting::Linear(VestingSchedule {
+            start: 1_000,
+            end: 2_000,
+            cliff: Some(1_200),
+        }),
+    )
+    .unwrap();
+
+    let donor = Address::generate(&env);
+    token.mint(&donor, &1_000);
+    client.contribute(&donor, &campaign_id, &1_000, &false);
+
+    validate(&env, &client, &campaign_id, 1);
+    validate(&env, &client, &campaign_id, 2);
+
+    // Only the immediate tranche is available before the schedule starts
+    assert_eq!(client.withdraw_milestone_funds(&campaign_id, &2), 400);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_100);
+    assert_eq!(
+        client.try_withdraw_milestone_funds(&campaign_id, &2),
+        Err(Ok(Error::NoFundsToWithdraw))
+    );
+
+    // Halfway through the schedule, half of the 600 tranche has vested
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_500);
+    assert_eq!(client.withdraw_milestone_funds(&campaign_id, &2), 300);
+    assert_eq!(client.get_milestone(&campaign_id, &2).claimed_amount, 300);
+
+    // Cancelling pays the 150 vested since, and the unvested 150 stays in
+    // escrow for the donor
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_750);
+    client.cancel_campaign(&campaign_id);
+    assert_eq!(client.get_milestone(&campaign_id, &2).claimed_amount, 450);
+    assert_eq!(client.get_campaign(&campaign_id).withdrawable_amount, 0);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 3_000);
+    assert_eq!(
+        client.try_withdraw_milestone_funds(&campaign_id, &2),
+        Err(Ok(Error::InvalidCampaignStatus))
+    );
+    client.refund(&donor, &campaign_id);
+    assert_eq!(
+        token::Client::new(&env, &token.address).balance(&donor),
+        150
+    );
+}
+
+#[test]
+fn test_refunds_after_release_share_the_remaining_escrow() {
+    let env = Env::default();
+    let (client, token, campaign_id) = setup(&env);
+
+    add_milestone(&env, &client, &campaign_id, 500, Vesting::Immediate).unwrap();
+    add_milestone(&env, &client, &campaign_id, 1_000, Vesting::Immediate).unwrap();
+
+    let donors = [Address::generate(&env), Address::generate(&env)];
+    for (donor, amount) in donors.iter().zip([600, 400]) {
+        token.mint(donor, &amount);
+        client.contribute(donor, &campaign_id, &amount, &false);
+    }
+
+    validate(&env, &client, &campaign_id, 1);
+    assert_eq!(client.withdraw_milestone_funds(&campaign_id, &1), 500);
+    client.cancel_campaign(&campaign_id);
+
+    // Only the unreleased half of the escrow goes back to contributors
+    let balance = token::Client::new(&env, &token.address);
+    assert_eq!(client.preview_refund(&donors[0], &campaign_id).amount, 300);
+    client.refund(&donors[0], &campaign_id);
+    client.refund(&donors[1], &campaign_id);
+    assert_eq!(balance.balance(&donors[0]), 300);
+    assert_eq!(balance.balance(&donors[1]), 200);
+    assert_eq!(balance.balance(&client.address), 0);
+}
+
+#[test]
+fn test_invalid_vesting_schedules_are_rejected() {
+    let env = Env::default();
+    let (client, _, campaign_id) = setup(&env);
+
+    let empty = Vesting::Linear(VestingSchedule {
+        start: 2_000,
+        end: 2_000,
+        cliff: None,
+    });
+    assert_eq!(
+        add_milestone(&env, &client, &campaign_id, 500, empty),
+        Err(Error::InvalidVestingSchedule)
+    );
+
+    let late_cliff = Vesting::Linear(VestingSchedule {
+        start: 1_000,
+        end: 2_000,
+        cliff: Some(2_500),
+    });
+    assert_eq!(
+        add_milestone(&env, &client, &campaign_id, 500, late_cliff),
+        Err(Error::InvalidVestingSchedule)
+    );
+}
@@ -468,11 +468,7 @@ impl MilestoneNftContract {
             .instance()
             .get(&DataKey::NextTokenId)
             .unwrap_or(1);
-        if next_id > 1 {
-            next_id - 1
-        } else {
-            0
-        }
+        next_id.saturating_sub(1)
     }
 
     /// Helper function to check if contract is initialized
@@ -502,7 +498,7 @@ mod tests {
             validated: false,
         };
 
-        assert_eq!(metadata.validated, false);
+        assert!(!metadata.validated);
         assert_eq!(metadata.timestamp, 1234567890);
         assert_eq!(metadata.uri.len(), 37);
         assert_eq!(metadata.description.len(), 25);
@@ -573,7 +569,5 @@ mod tests {
             DataKey::OperatorApproval(Address::generate(&env), Address::generate(&env));
         let _next_id_key = DataKey::NextTokenId;
         let _cf_key = DataKey::CrowdfundingContract;
-
-        assert!(true);
     }
 }